@@ -6,7 +6,7 @@ use syn::{
     braced, bracketed, parenthesized, parse::{discouraged::Speculative, Parse, ParseStream, Parser}, punctuated::{Pair, Punctuated}, Expr, Ident, LitStr, Token
 };
 use crate::{
-    types::{ArrayType, ClassPath, InnerType, JavaPrimitive, SigType, SpecialCaseConversion, Type, NULL_KEYWORD},
+    types::{ArrayType, ClassPath, InnerType, JavaPrimitive, RustPrimitive, RustType, SigType, Signature, SpecialCaseConversion, Type, NULL_KEYWORD},
     utils::{first_char_uppercase, gen_signature, join_spans, merge_errors, Spanned}
 };
 
@@ -23,97 +23,123 @@ pub fn jni_call(call: MethodCall) -> TokenStream {
         let call_failed_msg = match &call.call_type {
             Either::Left(StaticMethod(path))
                 => format!("Failed to call static method {name}() on {}: {{err}}", path.to_string()),
-            Either::Right(ObjectMethod(_)) => format!("Failed to call {name}(): {{err}}"),
-        };
-        // Induce panic when the returned value is not the expected type
-        let incorrect_type_msg = format!("Expected {name}() to return {}: {{err}}", call.return_type.inner());
-        let sig_char = Ident::new(&call.return_type.sig_char().to_string(), call.return_type.span());
-        // Apply special case conversion
-        // Also convert value to Option<_> if it is any kind of Object because it could be null.
-        let conversion = {
-            let conversion = call.return_type.inner().special_case_conversions(quote!(v));
-
-            if call.return_type.sig_char().to_string().as_str() == "l" {
-                match conversion {
-                    Some(conversion) => quote! { .map(|v| (!v.is_null()).then(|| #conversion)) },
-                    None => quote! { .map(|v| (!v.is_null()).then_some(v)) },
-                }
-            } else {
-                match conversion {
-                    Some(conversion) => quote! { .map(|v| #conversion) },
-                    None => quote!()
-                }
-            }
+            Either::Right(ObjectMethod(..)) => format!("Failed to call {name}(): {{err}}"),
         };
+        let conversion = gen_value_conversion(
+            call.return_type.inner(),
+            &format!("Expected {name}() to return {}: {{err}}", call.return_type.inner()),
+            &format!("Expected Object returned by {name}() to not be NULL"),
+        );
 
         quote! {
             .unwrap_or_else(|err| panic!(#call_failed_msg))
-            .#sig_char() #conversion
-            .unwrap_or_else(|err| panic!(#incorrect_type_msg))
+            #conversion
         }
     };
 
-    // Build the macro function call
+    // Build the macro function call.
+    // The Class and Method IDs are cached in a static so that they are only looked up the first time.
+    let return_type = gen_jni_return_type(call.return_type.inner());
     let jni_call = match &call.call_type {
         Either::Left(StaticMethod(class)) => {
             let class = LitStr::new(&class.to_jni_class_path(), class.span());
-            quote! { env.call_static_method(#class, #name, #signature, #arguments) }
+            quote! {
+                static __CACHE: ::ez_jni::__cache::IdCache<::jni::objects::JStaticMethodID> = ::ez_jni::__cache::IdCache::new();
+                let __call = __CACHE.static_method(env.borrow_mut(), #class, #name, #signature)
+                    .and_then(|(__class, __id)| unsafe { env.call_static_method_unchecked(<&::jni::objects::JClass>::from(__class.as_obj()), __id, #return_type, #arguments) });
+            }
         }
-        Either::Right(ObjectMethod(object)) => quote! {
-            env.call_method(&(#object), #name, #signature, #arguments)
+        Either::Right(object) => {
+            let object_var = object.gen_object_var();
+            match &call.super_class {
+                Some(class) => {
+                    let class = LitStr::new(&class.to_jni_class_path(), class.span());
+                    quote! {
+                        static __CACHE: ::ez_jni::__cache::IdCache<::jni::objects::JMethodID> = ::ez_jni::__cache::IdCache::new();
+                        #object_var
+                        let __call = __CACHE.nonvirtual_method(env.borrow_mut(), #class, #name, #signature)
+                            .and_then(|(__class, __id)| unsafe { ::ez_jni::utils::__call_nonvirtual_method_unchecked(env.borrow_mut(), __object, <&::jni::objects::JClass>::from(__class.as_obj()), __id, #return_type, #arguments) });
+                    }
+                },
+                None => quote! {
+                    static __CACHE: ::ez_jni::__cache::IdCache<::jni::objects::JMethodID> = ::ez_jni::__cache::IdCache::new();
+                    #object_var
+                    let __call = __CACHE.method(env.borrow_mut(), __object, #name, #signature)
+                        .and_then(|__id| unsafe { env.call_method_unchecked(__object, __id, #return_type, #arguments) });
+                },
+            }
         },
     };
 
-    let non_null_msg = format!("Expected Object returned by {name}() to not be NULL");
     // The class or object that the method is being called on. Used for panic message.
     let target = match call.call_type {
         Either::Left(StaticMethod(class)) => {
             let class = class.to_jni_class_path();
             quote! { ::either::Either::Left(#class) }
         }
-        Either::Right(ObjectMethod(obj)) => quote! { ::either::Either::Right(&(#obj)) },
+        Either::Right(ObjectMethod(..)) => quote! { ::either::Either::Right(__object) },
     };
     // The Initial JNI call, before any types or errors are checked
     let initial = quote! {
         use ::std::borrow::BorrowMut as _;
         #param_vars
-        let __call = #jni_call;
+        #jni_call
     };
     match call.return_type {
         // For return types that are not Result
-        Return::Assertive(ty) => match ty {
-            ReturnableType::Assertive(InnerType::Object(_)) | ReturnableType::Array(_) => quote! { {
-                #initial
-                ::ez_jni::__throw::panic_uncaught_exception(env.borrow_mut(), #target, #name);
-                __call #common
-                    .unwrap_or_else(|| panic!(#non_null_msg))
-            } },
-            ReturnableType::Void(_)
-            | ReturnableType::Assertive(_)
-            | ReturnableType::Option(_) => quote! { {
-                #initial
-                ::ez_jni::__throw::panic_uncaught_exception(env.borrow_mut(), #target, #name);
-                __call #common
-            } },
-        }
+        Return::Assertive(_) => quote! { {
+            #initial
+            ::ez_jni::__throw::panic_uncaught_exception(env.borrow_mut(), #target, #name);
+            __call #common
+        } },
         // Move the result of the method call to a Result if the caller expects that the method could throw.
-        Return::Result { ty, err_ty, ..} => match ty {
-            ReturnableType::Assertive(InnerType::Object(_)) | ReturnableType::Array(_) => quote! { {
-                #initial
-                ::ez_jni::__throw::catch::<#err_ty>(env.borrow_mut())
-                    .map(|_| __call #common
-                        .unwrap_or_else(|| panic!(#non_null_msg))
-                    )
-            } },
-            ReturnableType::Void(_)
-            | ReturnableType::Assertive(_)
-            | ReturnableType::Option(_) => quote! { {
-                #initial
-                ::ez_jni::__throw::catch::<#err_ty>(env.borrow_mut())
-                    .map(|_| __call #common)
-            } },
+        Return::Result { err_ty, .. } => quote! { {
+            #initial
+            ::ez_jni::__throw::catch::<#err_ty>(env.borrow_mut())
+                .map(|_| __call #common)
+        } },
+    }
+}
+
+/// Generates the *method chain* that converts the `JValueOwned` returned by a JNI call to the Rust type of **ty**.
+/// 
+/// The chain will `panic!` with **incorrect_type_msg** if the value is not of the expected type,
+/// or with **non_null_msg** if **ty** asserts that the *Object* is not `null` but it was.
+pub fn gen_value_conversion(ty: &ReturnableType, incorrect_type_msg: &str, non_null_msg: &str) -> TokenStream {
+    let sig_char = Ident::new(&ty.sig_char().to_string(), ty.span());
+    // Apply special case conversion
+    // Also convert value to Option<_> if it is any kind of Object because it could be null.
+    let conversion = {
+        let conversion = ty.special_case_conversions(quote!(v));
+
+        if ty.sig_char().to_string().as_str() == "l" {
+            match conversion {
+                Some(conversion) => quote! { .map(|v| (!v.is_null()).then(|| #conversion)) },
+                None => quote! { .map(|v| (!v.is_null()).then_some(v)) },
+            }
+        } else {
+            match conversion {
+                Some(conversion) => quote! { .map(|v| #conversion) },
+                None => quote!()
+            }
         }
-        
+    };
+    // Objects that are not Option must not be null
+    let non_null = match ty {
+        // Rust types that are converted from a primitive can't be null
+        ReturnableType::Rust(rust_ty) if rust_ty.primitive().is_some() => quote!(),
+        ReturnableType::Assertive(InnerType::Object(_)) | ReturnableType::Array(_) | ReturnableType::Rust(_) => quote! {
+            .unwrap_or_else(|| panic!(#non_null_msg))
+        },
+        ReturnableType::Void(_)
+        | ReturnableType::Assertive(_)
+        | ReturnableType::Option(_) => quote!(),
+    };
+
+    quote! {
+        .#sig_char() #conversion
+        .unwrap_or_else(|err| panic!(#incorrect_type_msg))
+        #non_null
     }
 }
 
@@ -123,14 +149,26 @@ pub fn jni_call_constructor(call: ConstructorCall) -> TokenStream {
     let signature = gen_signature(call.parameters.iter(), &Return::new_void(Span::call_site()));
     let param_vars = gen_arg_vars_defs(call.parameters.iter());
     let arguments = gen_arguments(call.parameters.iter());
-    let method_name = format!("constructor{}", signature.value());
-    let call_failed_msg = format!("Failed to call constructor {} on {}: {{err}}", signature.value(), class.to_token_stream());
+    // The signature is not included in the messages if it is only known at runtime
+    let (method_name, call_failed_msg) = match &signature {
+        Signature::Static(signature) => (
+            format!("constructor{}", signature.value()),
+            format!("Failed to call constructor {} on {}: {{err}}", signature.value(), class.to_token_stream()),
+        ),
+        Signature::Runtime(_) => (
+            "constructor".to_string(),
+            format!("Failed to call constructor on {}: {{err}}", class.to_token_stream()),
+        ),
+    };
     
-    // The Initial JNI call, before any types or errors are checked
+    // The Initial JNI call, before any types or errors are checked.
+    // The Class and constructor ID are cached in a static so that they are only looked up the first time.
     let initial = quote! {
         use ::std::borrow::BorrowMut as _;
         #param_vars
-        let __call = env.new_object(#class, #signature, #arguments);
+        static __CACHE: ::ez_jni::__cache::IdCache<::jni::objects::JMethodID> = ::ez_jni::__cache::IdCache::new();
+        let __call = __CACHE.constructor(env.borrow_mut(), #class, #signature)
+            .and_then(|(__class, __id)| unsafe { env.new_object_unchecked(<&::jni::objects::JClass>::from(__class.as_obj()), __id, #arguments) });
     };
     match call.err_type {
         Some(err) => quote! { {
@@ -152,27 +190,35 @@ pub fn jni_call_constructor(call: ConstructorCall) -> TokenStream {
 /// See [`crate::call`] for an example.
 pub struct MethodCall {
     pub call_type: Either<StaticMethod, ObjectMethod>,
+    /// The Class whose implementation of the method is called (a *non-virtual call*) when using `super(Class)`.
+    /// Can only be [`Some`] if the call is for an [`ObjectMethod`].
+    pub super_class: Option<ClassPath>,
     pub method_name: Ident,
     pub parameters: Punctuated<Parameter, Token![,]>,
     pub return_type: Return,
 }
 impl Parse for MethodCall {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let (call_type, method_name) = if input.parse::<Token![static]>().is_ok() {
+        let (call_type, super_class, method_name) = if input.parse::<Token![static]>().is_ok() {
             let (class_path, method_name) = ClassPath::parse_with_trailing_method(input)?;
-            (Either::Left(StaticMethod(class_path)), method_name)
+            (Either::Left(StaticMethod(class_path)), None, method_name)
         } else {
-            (Either::Right(input.parse::<ObjectMethod>()?), input.parse()?)
+            // Check for non-virtual call, e.g. `super(me.author.Base) object.method()`
+            let super_class = if input.parse::<Token![super]>().is_ok() {
+                let class;
+                parenthesized!(class in input);
+                Some(class.parse::<ClassPath>()?)
+            } else {
+                None
+            };
+            (Either::Right(input.parse::<ObjectMethod>()?), super_class, input.parse()?)
         };
 
         Ok(Self {
             call_type,
+            super_class,
             method_name,
-            parameters: {
-                let arg_tokens;
-                parenthesized!(arg_tokens in input);
-                Punctuated::parse_terminated(&arg_tokens)?
-            },
+            parameters: Parameter::parse_list(input)?,
             return_type: {
                 input.parse::<Token![->]>()?;
                 input.parse()?
@@ -193,11 +239,7 @@ impl Parse for ConstructorCall {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(Self {
             class: input.parse()?,
-            parameters: {
-                let arg_tokens;
-                parenthesized!(arg_tokens in input);
-                Punctuated::parse_terminated(&arg_tokens)?
-            },
+            parameters: Parameter::parse_list(input)?,
             err_type: {
                 let fork = input.fork();
                 match fork.parse::<Ident>() {
@@ -221,22 +263,60 @@ pub struct StaticMethod(pub ClassPath);
 /// The call is for a Method of an existing Object, stored in a variable.
 /// If the object is more than an Ident, it must be enclosed in `parenthesis` or `braces`.
 /// e.g. `object.methodName(...)` or `(something.object).methodName(...)`.
-pub struct ObjectMethod(pub TokenStream);
+/// 
+/// The object in parenthesis can be followed by `as` and a [`ClassPath`] (e.g. `(object as java.util.List).size()`),
+/// which checks that the Object is an instance of that Class before the call is made.
+pub struct ObjectMethod(pub TokenStream, pub Option<ClassPath>);
+impl ObjectMethod {
+    /// Generates the definition of the `__object` variable, which holds a reference to the Object.
+    /// 
+    /// If the Object was *cast*, this also checks that the Object is an instance of that Class.
+    pub fn gen_object_var(&self) -> TokenStream {
+        let object = &self.0;
+        let cast_check = self.1.as_ref().map(|class| {
+            let class = LitStr::new(&class.to_jni_class_path(), class.span());
+            quote! { ::ez_jni::utils::__check_cast(__object, #class, env.borrow_mut()); }
+        });
+
+        quote! {
+            let __object = &(#object);
+            #cast_check
+        }
+    }
+
+    /// Parses the tokens inside the parenthesis, which could end with `as` and a [`ClassPath`].
+    fn parse_cast(input: ParseStream) -> syn::Result<(TokenStream, Option<ClassPath>)> {
+        let tokens = input.parse::<TokenStream>()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        let as_index = tokens.iter()
+            .rposition(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "as"));
+
+        if let Some(i) = as_index {
+            // Not a cast if the tokens after `as` are not a ClassPath (e.g. a Rust cast like `as usize`)
+            if let Ok(class) = syn::parse2::<ClassPath>(tokens[i + 1..].iter().cloned().collect()) {
+                return Ok((tokens[..i].iter().cloned().collect(), Some(class)));
+            }
+        }
+
+        Ok((tokens.into_iter().collect(), None))
+    }
+}
 impl Parse for ObjectMethod {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let expr = if input.peek(syn::token::Paren) {
+        let (expr, cast) = if input.peek(syn::token::Paren) {
             let inner;
             parenthesized!(inner in input);
-            inner.parse::<TokenStream>()?
+            Self::parse_cast(&inner)?
         } else if input.peek(syn::token::Brace) {
             let inner;
             braced!(inner in input);
-            inner.parse::<TokenStream>()?
+            (inner.parse::<TokenStream>()?, None)
         } else {
-            input.parse::<Ident>()?.to_token_stream()
+            (input.parse::<Ident>()?.to_token_stream(), None)
         };
         input.parse::<Token![.]>()?;
-        Ok(Self(expr))
+        Ok(Self(expr, cast))
     }
 }
 
@@ -248,6 +328,29 @@ pub struct Parameter {
     value: ParamValue,
 }
 impl Parameter {
+    /// Create a [`Parameter`] from a **type** and an *expression* that will be used as the **value**.
+    /// 
+    /// This is used when another macro builds a JNI call without parsing the parameters from tokens (e.g. `derive(ToObject)`).
+    pub fn new(ty: Type, value: TokenStream) -> Self {
+        Self { ty, value: ParamValue::Value(value) }
+    }
+    /// Parses the comma-separated parameters of a method or constructor call, which are wrapped in parentheses.
+    ///
+    /// Checks that only the *last* parameter is a *varargs* parameter.
+    pub fn parse_list(input: ParseStream) -> syn::Result<Punctuated<Self, Token![,]>> {
+        let arg_tokens;
+        parenthesized!(arg_tokens in input);
+        let parameters = Punctuated::<Self, Token![,]>::parse_terminated(&arg_tokens)?;
+
+        merge_errors(parameters.iter()
+            .rev()
+            .skip(1)
+            .filter(|param| matches!(param.value, ParamValue::Varargs(_)))
+            .map(|param| syn::Error::new(param.span(), "Varargs parameter must be the last parameter."))
+        )?;
+
+        Ok(parameters)
+    }
     /// Conver the parameter to a `Jvalue` enum variant that will be used in the JNI call parameter list.
     /// The variant will have one of the parameter variables as the inner value.
     pub fn jni_variant(&self, var_name: Ident) -> TokenStream {
@@ -267,8 +370,13 @@ impl Parameter {
                 InnerType::JavaPrimitive { ident, ty } => (ident.span(), primitive_variant(*ty, ident.span())),
                 InnerType::RustPrimitive { ident, ty } => (ident.span(), primitive_variant(JavaPrimitive::from(*ty), ident.span())),
                 InnerType::Object(class) => (class.span(), quote!(Object)),
+                InnerType::Array(array) => (array.span(), quote!(Object)),
             },
             Type::Array(ty) => (ty.span(), quote!(Object)),
+            Type::Rust(ty) => match ty.primitive() {
+                Some(primitive) => (ty.span(), primitive_variant(primitive, ty.span())),
+                None => (ty.span(), quote!(Object)),
+            },
         };
         let mut tt = quote! {};
         tt.append_all(quote_spanned! {ty_span=> ::jni::objects::JValue::#ty_variant });
@@ -280,10 +388,11 @@ impl SigType for Parameter {
     fn sig_char(&self) -> Ident {
         self.ty.sig_char()
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         self.ty.sig_type()
     }
 }
+static NULL_ERROR: &str = "Can't use 'null' as value of primitive parameter type.";
 impl Parse for Parameter {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         // Detect if user uses Option and disallow it
@@ -293,6 +402,32 @@ impl Parse for Parameter {
             }
         }
         let ty = input.parse::<Type>()?;
+
+        // Varargs parameter, e.g. `java.lang.Object...(a, b, c)`
+        if let Ok(dots) = input.parse::<Token![...]>() {
+            let ty = match ty {
+                Type::Single(ty) => ArrayType { ty },
+                Type::Array(_) => return Err(syn::Error::new_spanned(dots, "The type of a varargs parameter can't be an Array; use the element type instead.")),
+                Type::Rust(ty) => return Err(syn::Error::new(ty.span(), "The type of a varargs parameter can't be a Rust type.")),
+            };
+            let value_tokens;
+            parenthesized!(value_tokens in input);
+            let elements = Punctuated::<Expr, Token![,]>::parse_terminated(&value_tokens)?
+                .into_pairs()
+                .map(|pair| pair.into_tuple())
+                .map(|(expr, punct)| Pair::new(ArrayElement::from_expr(expr), punct))
+                .collect::<Punctuated<ArrayElement, Token![,]>>();
+
+            if let InnerType::JavaPrimitive { .. } | InnerType::RustPrimitive { .. } = &ty.ty {
+                merge_errors(elements.iter()
+                    .filter_map(|elem| elem.null())
+                    .map(|null| syn::Error::new(null.span(), NULL_ERROR))
+                )?;
+            }
+
+            return Ok(Self { ty: Type::Array(ty), value: ParamValue::Varargs(elements) })
+        }
+
         let value_tokens;
         parenthesized!(value_tokens in input);
 
@@ -302,24 +437,29 @@ impl Parse for Parameter {
 
         let value = value_tokens.parse::<ParamValue>()?;
 
-        static NULL_ERROR: &str = "Can't use 'null' as value of primitive parameter type.";
         // Check that the correct Value was passed in for the correct Type
         match &value {
             // null can be used for Object and Array
             ParamValue::Null(null) => match &ty {
-                Type::Single(InnerType::Object(_)) | Type::Array(_) => { },
-                Type::Single(InnerType::JavaPrimitive { .. } | InnerType::RustPrimitive { .. })
+                Type::Single(InnerType::Object(_) | InnerType::Array(_)) | Type::Array(_) => { },
+                Type::Rust(rust_ty) if rust_ty.primitive().is_none() => { },
+                Type::Single(InnerType::JavaPrimitive { .. } | InnerType::RustPrimitive { .. }) | Type::Rust(_)
                     => return Err(syn::Error::new(null.span(), NULL_ERROR))
             },
             // ArrayNull can only be used with Array of Object
             ParamValue::ArrayNull(array) => match &ty {
-                Type::Single(_) => return Err(syn::Error::new(value.span(), "Can't pass an Array value to a parameter that is not an Array.")),
+                Type::Single(_) | Type::Rust(_) => return Err(syn::Error::new(value.span(), "Can't pass an Array value to a parameter that is not an Array.")),
                 // Create an error out of all the `null` values
                 Type::Array(ArrayType { ty: InnerType::JavaPrimitive { .. } | InnerType::RustPrimitive { .. } })
                     => return Err(merge_errors(array.iter()
                         .filter_map(|elem| elem.null())
                         .map(|null| syn::Error::new(null.span(), NULL_ERROR))
                     ).expect_err("Got ParamValue::ArrayNull, but did not contain any nulls?")),
+                Type::Array(ArrayType { ty: InnerType::Array(_) })
+                    => return Err(merge_errors(array.iter()
+                        .filter_map(|elem| elem.null())
+                        .map(|null| syn::Error::new(null.span(), "Can't use 'null' as an element of an Array of Arrays."))
+                    ).expect_err("Got ParamValue::ArrayNull, but did not contain any nulls?")),
                 Type::Array(ArrayType { ty: InnerType::Object(_) }) => { }
             },
             _ => {}
@@ -378,7 +518,52 @@ impl ToTokens for Parameter {
                             .unwrap_or(value)
                     },
                 Type::Array(ArrayType { ty: _, .. }) => panic!("Unreachable code; 'null' can't be used when ty is a Primitive"),
-                Type::Single(_) => panic!("Unreachable code; Can't have Array value but non-array type"),
+                Type::Single(_) | Type::Rust(_) => panic!("Unreachable code; Can't have Array value but non-array type"),
+            },
+            ParamValue::Varargs(elements) => {
+                let array = match &self.ty {
+                    Type::Array(array) => array,
+                    Type::Single(_) | Type::Rust(_) => panic!("Unreachable code; The type of varargs is always an Array"),
+                };
+                let len = elements.len();
+                // Build an Array literal of the element type, which is then converted like any other Array value
+                let value = match &array.ty {
+                    // Each element can be of a different Rust type, so convert them all to JObject first
+                    InnerType::Object(_) => {
+                        let elems = elements.iter()
+                            .map(|elem| match elem {
+                                ArrayElement::Null(null) => quote_spanned!(null.span()=> ::jni::objects::JObject::null()),
+                                ArrayElement::Value(value) => quote_spanned!(value.span()=> (#value).to_object(env.borrow_mut())),
+                            });
+                        quote_spanned! {self.value.span()=> {
+                            use ::std::borrow::BorrowMut as _;
+                            use ::ez_jni::ToObject as _;
+                            let _varargs: [::jni::objects::JObject; #len] = [#(#elems),*];
+                            _varargs
+                        } }
+                    },
+                    InnerType::JavaPrimitive { .. } | InnerType::RustPrimitive { .. } => {
+                        let rust_ty = match &array.ty {
+                            InnerType::JavaPrimitive { ty, .. } => RustPrimitive::from(*ty),
+                            InnerType::RustPrimitive { ty, .. } => *ty,
+                            InnerType::Object(_) | InnerType::Array(_) => unreachable!(),
+                        };
+                        let rust_ty = Ident::new(&rust_ty.to_string(), array.span());
+                        // Parameter::parse() checked that there are no `null`s
+                        let elems = elements.iter()
+                            .filter_map(|elem| match elem {
+                                ArrayElement::Value(value) => Some(value),
+                                ArrayElement::Null(_) => None,
+                            });
+                        quote_spanned! {self.value.span()=> {
+                            let _varargs: [#rust_ty; #len] = [#(#elems),*];
+                            _varargs
+                        } }
+                    },
+                    InnerType::Array(_) => panic!("Unreachable code; The element type of varargs can't be an Array"),
+                };
+                self.ty.convert_rust_to_java(&value)
+                    .unwrap_or(value)
             },
             ParamValue::Value(value) => self.ty.convert_rust_to_java(&value)
                 .unwrap_or_else(|| value.clone())
@@ -398,6 +583,12 @@ pub enum ParamValue {
     /// *value* [`ArrayNull`][ParamValue::ArrayNull] and *type* [`Type::is_primitive()`] are mutually exclusive.
     /// This is checked by [`Parameter::parse()`].
     ArrayNull(Punctuated<ArrayElement, Token![,]>),
+    /// The values of a *varargs* parameter (e.g. `java.lang.Object...(a, b, null)`),
+    /// which are packed into an Array of the parameter's element type.
+    ///
+    /// Elements can only be `null` if the element type is an Object.
+    /// This is checked by [`Parameter::parse()`].
+    Varargs(Punctuated<ArrayElement, Token![,]>),
     Value(TokenStream)
 }
 pub enum ArrayElement {
@@ -405,6 +596,13 @@ pub enum ArrayElement {
     Value(Expr)
 }
 impl ArrayElement {
+    /// Converts an *expression* to [`ArrayElement::Null`] if it is the `null` keyword.
+    pub fn from_expr(expr: Expr) -> Self {
+        match Parser::parse2(Ident::parse, expr.to_token_stream()) {
+            Ok(ident) if ident == NULL_KEYWORD => Self::Null(ident),
+            _ => Self::Value(expr)
+        }
+    }
     /// Get the Null variant.
     pub fn null(&self) -> Option<&Ident> {
         match self {
@@ -417,7 +615,8 @@ impl Spanned for ParamValue {
     fn span(&self) -> Span {
         match self {
             Self::Null(null) => null.span(),
-            Self::ArrayNull(array) => join_spans(
+            Self::ArrayNull(array)
+            | Self::Varargs(array) => join_spans(
                 array.pairs()
                     .map(|pair| join_spans([match pair.value() {
                         ArrayElement::Null(null) => null.span(),
@@ -431,18 +630,11 @@ impl Spanned for ParamValue {
 impl Parse for ParamValue {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         fn parse_array(input: ParseStream) -> syn::Result<Punctuated<ArrayElement, Token![,]>> {
-            fn expr_to_element(expr: Expr) -> ArrayElement {
-                match Parser::parse2(Ident::parse, expr.to_token_stream()) {
-                    Ok(ident) if ident.to_string() == NULL_KEYWORD => ArrayElement::Null(ident),
-                    _ => ArrayElement::Value(expr)
-                }
-            }
-
             let array = input.parse::<syn::ExprArray>()?;
 
             Ok(array.elems.into_pairs()
                 .map(|pair| pair.into_tuple())
-                .map(|(expr, punct)| Pair::new(expr_to_element(expr), punct))
+                .map(|(expr, punct)| Pair::new(ArrayElement::from_expr(expr), punct))
                 .collect::<Punctuated<_, _>>()
             )
         }
@@ -481,6 +673,12 @@ impl Debug for ParamValue {
                     ArrayElement::Value(value) => value.to_token_stream().to_string(),
                 }).collect::<Vec<_>>())
                 .finish(),
+            Self::Varargs(array) => f.debug_tuple("ParamValue::Varargs")
+                .field(&array.iter().map(|elem| match elem {
+                    ArrayElement::Null(null) => null.to_token_stream().to_string(),
+                    ArrayElement::Value(value) => value.to_token_stream().to_string(),
+                }).collect::<Vec<_>>())
+                .finish(),
             Self::Value(value) => f.debug_tuple("ParamValue::Value")
                 .field(&value.to_string())
                 .finish()
@@ -526,7 +724,7 @@ impl SigType for Return {
             | Self::Result { ty, .. } => ty.sig_char()
         }
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         match self {
             Self::Assertive(ty)
             | Self::Result{ ty, .. } => ty.sig_type()
@@ -600,13 +798,18 @@ pub enum ReturnableType {
     Array(ReturnArray),
     /// A Nullable **Object** or **Array**.
     Option(OptionType),
+    /// A Rust type that is converted from a Java Object with `FromObject`.
+    /// 
+    /// Like [`Assertive`][ReturnableType::Assertive], this will cause a `panic!` if `call!` returns `null`.
+    Rust(RustType),
 }
 /// A type that can be in the `Option` of the [*return type*](ReturnableType).
 /// Can't have *primitive* types themselves, but can have *array of primitive*.
 #[derive(Debug)]
 pub enum OptionType {
     Object(ClassPath),
-    Array(ReturnArray)
+    Array(ReturnArray),
+    Rust(RustType),
 }
 impl ReturnableType {
     /// Handle special cases of the call's return value.
@@ -618,6 +821,8 @@ impl ReturnableType {
             Self::Array(array) => array.special_case_conversions(value),
             Self::Option(OptionType::Array(array)) => array.special_case_conversions(value),
             Self::Option(OptionType::Object(class)) => class.convert_java_to_rust(&value),
+            Self::Rust(ty)
+            | Self::Option(OptionType::Rust(ty)) => ty.convert_java_to_rust(&value),
             Self::Void(_) => None,
         }
     }
@@ -630,15 +835,19 @@ impl SigType for ReturnableType {
             Self::Array(array) => array.to_array_type().sig_char(),
             Self::Option(OptionType::Array(array)) => array.to_array_type().sig_char(),
             Self::Option(OptionType::Object(class)) => class.sig_char(),
+            Self::Rust(ty)
+            | Self::Option(OptionType::Rust(ty)) => ty.sig_char(),
         }
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         match self {
-            Self::Void(ident) => LitStr::new("V", ident.span()),
+            Self::Void(ident) => Signature::Static(LitStr::new("V", ident.span())),
             Self::Assertive(ty) => ty.sig_type(),
             Self::Array(array) => array.to_array_type().sig_type(),
             Self::Option(OptionType::Array(array)) => array.to_array_type().sig_type(),
             Self::Option(OptionType::Object(class)) => class.sig_type(),
+            Self::Rust(ty)
+            | Self::Option(OptionType::Rust(ty)) => ty.sig_type(),
         }
     }
 }
@@ -650,6 +859,8 @@ impl Spanned for ReturnableType {
             Self::Array(array) => array.span(),
             Self::Option(OptionType::Array(array)) => array.span(),
             Self::Option(OptionType::Object(class)) => class.span(),
+            Self::Rust(ty)
+            | Self::Option(OptionType::Rust(ty)) => ty.span(),
         }
     }
 }
@@ -673,9 +884,11 @@ impl Parse for ReturnableType {
         }
         drop(fork);
 
-        // Attempt to parse Array, and finally InnerType (primitive or Object).
+        // Attempt to parse Array, Rust type, and finally InnerType (primitive or Object).
         Ok(if input.lookahead1().peek(syn::token::Bracket) {
             Self::Array(input.parse()?)
+        } else if RustType::peek(input) {
+            Self::Rust(input.parse()?)
         } else {
             Self::Assertive(input.parse()?)
         })
@@ -696,6 +909,12 @@ impl Parse for OptionType {
                 // Check if the Type is wrapped in Brackets (Array)
                 let option_ty = if input.lookahead1().peek(syn::token::Bracket) {
                     Self::Array(input.parse()?)
+                } else if RustType::peek(input) {
+                    let ty = input.parse::<RustType>()?;
+                    if let Some(primitive) = ty.primitive() {
+                        return Err(syn::Error::new(ty.span(), format!("Option can't be used with primitives, only Classes. In call!, {ty} is converted from the primitive '{primitive}'.")))
+                    }
+                    Self::Rust(ty)
                 } else {
                     match input.parse::<InnerType>()? {
                         InnerType::Object(class) => Self::Object(class),
@@ -717,11 +936,11 @@ impl Display for ReturnableType {
         match self {
             Self::Void(ident) => Display::fmt(ident, f),
             Self::Assertive(ty)
-            | Self::Array(ReturnArray::Assertive(ty))
-            | Self::Option(OptionType::Array(ReturnArray::Assertive(ty))) => Display::fmt(ty, f),
-            Self::Array(ReturnArray::Option(class))
-            | Self::Option(OptionType::Object(class))
-            | Self::Option(OptionType::Array(ReturnArray::Option(class))) => Display::fmt(class, f),
+            | Self::Array(ReturnArray::Assertive(ty) | ReturnArray::Option(ty))
+            | Self::Option(OptionType::Array(ReturnArray::Assertive(ty) | ReturnArray::Option(ty))) => Display::fmt(ty, f),
+            Self::Option(OptionType::Object(class)) => Display::fmt(class, f),
+            Self::Rust(ty)
+            | Self::Option(OptionType::Rust(ty)) => Display::fmt(ty, f),
         }
     }
 }
@@ -730,16 +949,15 @@ impl Display for ReturnableType {
 pub enum ReturnArray {
     /// Regular Array with **non-null** values.
     Assertive(InnerType),
-    /// Array with **nullable** values.
-    // TODO: Use OptionType instead when there is support for multi-dimensional Arrays
-    Option(ClassPath),
+    /// Array with **nullable** values, which can be Objects or Arrays (never primitives).
+    Option(InnerType),
 }
 impl ReturnArray {
     /// Creates an ArrayType to avoid reimplementing the same methods for ReturnArray.
-    fn to_array_type(&self) -> ArrayType {
+    pub fn to_array_type(&self) -> ArrayType {
         ArrayType { ty: match self {
-            Self::Assertive(ty) => ty.clone(),
-            Self::Option(class) => InnerType::Object(class.clone())
+            Self::Assertive(ty)
+            | Self::Option(ty) => ty.clone(),
         } }
     }
     /// Handle special cases of the call's return value,
@@ -749,16 +967,17 @@ impl ReturnArray {
     pub fn special_case_conversions(&self, value: TokenStream) -> Option<TokenStream> {
         match self {
             Self::Assertive(_) => self.to_array_type().convert_java_to_rust(&value),
-            Self::Option(class) => Some({
-                // For some reason, class.getName() returns a ClassPath with .dots. instead of /slashes/, so can't use sig_type().
+            Self::Option(ty) => Some({
+                // For some reason, class.getName() returns a ClassPath with .dots. instead of /slashes/, so can't use sig_type() directly.
                 // This is the only place where this happens. why??
-                let inner_ty = LitStr::new(&format!("L{};", class.to_string()), class.span());
+                let inner_ty = ty.sig_type();
+                let inner_ty = LitStr::new(&inner_ty.value().replace('/', "."), inner_ty.span());
 
-                // The inner Class of the array might require some conversion
+                // The inner Class (or Array) of the array might require some conversion
                 let conversion = {
                     let element_tokens = quote_spanned!(value.span()=> _element); 
                     // Convert using the variable
-                    class.convert_java_to_rust(&element_tokens)
+                    ty.convert_java_to_rust(&element_tokens)
                         .unwrap_or(element_tokens)
                 };
 
@@ -785,8 +1004,8 @@ impl ReturnArray {
 impl Spanned for ReturnArray {
     fn span(&self) -> Span {
         match self {
-            Self::Assertive(ty) => ty.span(),
-            Self::Option(class) => class.span(),
+            Self::Assertive(ty)
+            | Self::Option(ty) => ty.span(),
         }
     }
 }
@@ -798,8 +1017,10 @@ impl Parse for ReturnArray {
 
         if fork.parse::<Ident>().is_ok_and(|ident| ident.to_string() == "Option") {
             match inner.parse::<OptionType>()? {
-                OptionType::Object(class) => Ok(Self::Option(class)),
-                OptionType::Array(array) => Err(syn::Error::new(array.span(), "Multi-dimensional Arrays not yet supported.")),
+                OptionType::Object(class) => Ok(Self::Option(InnerType::Object(class))),
+                OptionType::Array(Self::Assertive(ty)) => Ok(Self::Option(InnerType::Array(Box::new(ArrayType { ty })))),
+                OptionType::Array(array @ Self::Option(_)) => Err(syn::Error::new(array.span(), "Only the outermost Array can have nullable elements.")),
+                OptionType::Rust(ty) => Err(syn::Error::new(ty.span(), "A Rust type can't be used here; use a Rust type that contains it instead (e.g. 'Vec<Option<T>>' instead of '[Option<T>]').")),
             }
         } else {
             Ok(Self::Assertive(inner.parse()?))
@@ -816,7 +1037,7 @@ impl Parse for ReturnArray {
 /// 
 /// Putting the arguments in variables prevents them from being dropped (since JValue takes references),
 /// and mitigates borrow checker error if the argument expression *borrows env* (since the call itself borrows &mut env).
-fn gen_arg_vars_defs<'a>(params: impl Iterator<Item = &'a Parameter>) -> TokenStream {
+pub fn gen_arg_vars_defs<'a>(params: impl Iterator<Item = &'a Parameter>) -> TokenStream {
     params
         .enumerate()
         .map(|(i, param)| {
@@ -833,11 +1054,31 @@ fn gen_arg_vars_defs<'a>(params: impl Iterator<Item = &'a Parameter>) -> TokenSt
 }
 /// Generates the argument array that will be passed to the JNI call,
 /// but the values are just the variables generated by [`gen_arg_vars_defs()`].
+/// 
+/// The values are *raw* `jvalue`s because the call uses one of the `*_unchecked` functions of `JNIEnv`.
 fn gen_arguments<'a>(params: impl Iterator<Item = &'a Parameter>) -> TokenStream {
     let params = params
         .enumerate()
         .map(|(i, param)| {
             param.jni_variant(Ident::new(&format!("__param_{i}"), param.span()))
         });
-    quote! { &[ #( #params ),* ] }
+    quote! { &[ #( (#params).as_jni() ),* ] }
+}
+/// Generates the `ReturnType` that is passed to the `*_unchecked` JNI call functions,
+/// which determines what kind of value the called method returns.
+fn gen_jni_return_type(ty: &ReturnableType) -> TokenStream {
+    let primitive = match ty.sig_char().to_string().as_str() {
+        "v" => "Void",
+        "z" => "Boolean",
+        "c" => "Char",
+        "b" => "Byte",
+        "s" => "Short",
+        "i" => "Int",
+        "j" => "Long",
+        "f" => "Float",
+        "d" => "Double",
+        _ => return quote! { ::jni::signature::ReturnType::Object },
+    };
+    let primitive = Ident::new(primitive, ty.span());
+    quote! { ::jni::signature::ReturnType::Primitive(::jni::signature::Primitive::#primitive) }
 }
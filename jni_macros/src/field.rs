@@ -0,0 +1,126 @@
+use either::Either;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::{Parse, ParseStream}, Ident, LitStr, Token};
+use crate::{
+    call::{gen_arg_vars_defs, gen_value_conversion, ObjectMethod, Parameter, ReturnableType, StaticMethod},
+    types::{ClassPath, SigType},
+    utils::Spanned,
+};
+
+/// Processes input for macro call [super::field!].
+pub fn jni_field(access: FieldAccess) -> TokenStream {
+    let name = LitStr::new(&access.name.to_string(), access.name.span());
+
+    match access.kind {
+        Either::Left(FieldGet(ty)) => {
+            let sig_ty = ty.sig_type();
+            let conversion = gen_value_conversion(
+                &ty,
+                &format!("Expected field {} to be {ty}: {{err}}", access.name),
+                &format!("Expected field {} to not be NULL", access.name),
+            );
+
+            match access.target {
+                Either::Left(StaticMethod(class)) => {
+                    let class = LitStr::new(&class.to_jni_class_path(), class.span());
+                    let failed_msg = format!("Failed to get static field {} of {}: {{err}}", access.name, class.value());
+
+                    quote! { {
+                        use ::std::borrow::BorrowMut as _;
+                        let __value = env.get_static_field(#class, #name, #sig_ty);
+                        ::ez_jni::__throw::panic_uncaught_exception(env.borrow_mut(), ::either::Either::Left(#class), #name);
+                        __value
+                            .unwrap_or_else(|err| panic!(#failed_msg))
+                            #conversion
+                    } }
+                },
+                Either::Right(object) => {
+                    let object_var = object.gen_object_var();
+                    quote! { {
+                        use ::std::borrow::BorrowMut as _;
+                        #object_var
+                        ::ez_jni::utils::get_field(__object, #name, #sig_ty, true, env.borrow_mut())
+                            .unwrap_or_else(|err| panic!("{err}"))
+                            #conversion
+                    } }
+                },
+            }
+        },
+        Either::Right(FieldSet(param)) => {
+            let sig_ty = param.sig_type();
+            let var = gen_arg_vars_defs(Some(&param).into_iter());
+            let variant = param.jni_variant(Ident::new("__param_0", access.name.span()));
+
+            match access.target {
+                Either::Left(StaticMethod(class)) => {
+                    let class = LitStr::new(&class.to_jni_class_path(), class.span());
+                    let failed_msg = format!("Failed to set static field {} of {}: {{err}}", access.name, class.value());
+
+                    quote! { {
+                        use ::std::borrow::BorrowMut as _;
+                        #var
+                        let __class = env.find_class(#class)
+                            .unwrap_or_else(|err| panic!(#failed_msg));
+                        let __field = env.get_static_field_id(&__class, #name, #sig_ty);
+                        ::ez_jni::__throw::panic_uncaught_exception(env.borrow_mut(), ::either::Either::Left(#class), #name);
+                        env.set_static_field(&__class, __field.unwrap_or_else(|err| panic!(#failed_msg)), #variant)
+                            .unwrap_or_else(|err| panic!(#failed_msg))
+                    } }
+                },
+                Either::Right(object) => {
+                    let object_var = object.gen_object_var();
+                    quote! { {
+                        use ::std::borrow::BorrowMut as _;
+                        #object_var
+                        #var
+                        ::ez_jni::utils::set_field(__object, #name, #sig_ty, #variant, true, env.borrow_mut())
+                            .unwrap_or_else(|err| panic!("{err}"))
+                    } }
+                },
+            }
+        },
+    }
+}
+
+/// Define a JNI access to a *member* of an Object or a *static field* of a Class,
+/// which is either **reading** or **writing** the *field*.
+///
+/// See [`crate::field`] for an example.
+pub struct FieldAccess {
+    pub target: Either<StaticMethod, ObjectMethod>,
+    pub name: Ident,
+    pub kind: Either<FieldGet, FieldSet>,
+}
+impl Parse for FieldAccess {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (target, name) = if input.parse::<Token![static]>().is_ok() {
+            let (class_path, name) = ClassPath::parse_with_trailing_method(input)?;
+            (Either::Left(StaticMethod(class_path)), name)
+        } else {
+            (Either::Right(input.parse::<ObjectMethod>()?), input.parse::<Ident>()?)
+        };
+
+        let lookahead = input.lookahead1();
+        let kind = if lookahead.peek(Token![->]) {
+            input.parse::<Token![->]>()?;
+            let ty = input.parse::<ReturnableType>()?;
+            if let ReturnableType::Void(void) = ty {
+                return Err(syn::Error::new(void.span(), "A field can't be of type void."))
+            }
+            Either::Left(FieldGet(ty))
+        } else if lookahead.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Either::Right(FieldSet(input.parse()?))
+        } else {
+            return Err(lookahead.error())
+        };
+
+        Ok(Self { target, name, kind })
+    }
+}
+
+/// Read the value of the field, which is converted to this type.
+pub struct FieldGet(pub ReturnableType);
+/// Write a value to the field, with the same syntax as a [`Parameter`] of `call!`.
+pub struct FieldSet(pub Parameter);
@@ -1,9 +1,10 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::{braced, parenthesized, parse::{Parse, ParseStream}, punctuated::Punctuated, Attribute, GenericParam, Generics, Ident, ItemFn, LifetimeParam, LitStr, Token};
+use syn::{braced, parenthesized, parse::{Parse, ParseStream}, punctuated::Punctuated, Attribute, GenericParam, Generics, Ident, ItemFn, LifetimeParam, Token};
 use crate::{
-    utils::{Spanned, gen_signature, take_class_attribute_required, merge_errors},
-    types::{ClassPath, RustPrimitive, SigType, InnerType}
+    utils::{Spanned, gen_signature, take_class_attribute, take_class_attribute_required, merge_errors},
+    call::{OptionType, Return, ReturnArray, ReturnableType},
+    types::{ClassPath, JavaPrimitive, RustPrimitive, SigType, Signature, SpecialCaseConversion, InnerType},
 };
 
 /// Processes the input for [`crate::jni_fns`].
@@ -23,16 +24,13 @@ pub fn jni_fn(input: ParseStream) -> syn::Result<Vec<ItemFn>> {
     merge_errors(errors)?;
 
     // Convert all JniFn to ItemFn 
-    Ok(inputs.into_iter()
+    inputs.into_iter()
         .map(|f| f.to_rust_fn())
         .collect()
-    )
 }
 
 // TODO: Allow generics in the arguments and return type if they are a Java Class
 
-// TODO: support arrays
-
 /// A rust function that uses Java types (or some Rust types) and is called by Java Code.
 /// 
 /// A [`JniFn`] is one that MUST be exported by the user library.
@@ -43,13 +41,16 @@ pub struct JniFn {
     class: ClassPath,
     pub name: Ident,
     pub lifetime: LifetimeParam,
+    /// Whether the function is an *instance method* (has a `self` receiver as its first argument).
+    /// The receiver Object is passed to the function as `this`.
+    pub receiver: Option<Token![self]>,
     pub inputs: Punctuated<JniFnArg, Token![,]>,
     // pub variadic: Option<Variadic>, Should this be allowed?
     pub output: JniReturn,
     pub content: TokenStream,
 }
 impl JniFn {
-    pub fn to_rust_fn(&self) -> ItemFn {
+    pub fn to_rust_fn(&self) -> syn::Result<ItemFn> {
         let name = {
             let class = self.class.to_string()
                 .replace('.', "_");
@@ -59,23 +60,57 @@ impl JniFn {
         };
 
         // Build a java method signature, something like (Ljava.lang.String;)I
+        // Rust types are not allowed in the arguments and return type, so the signature is always static
         let method_sig = gen_signature(self.inputs.iter().map(|i| &i.ty), &self.output).value();
 
         let attrs = &self.attrs;
         let lifetime = &self.lifetime;
         let inputs = &self.inputs;
         let output = &self.output;
+        // Arguments are converted to Rust values before the body runs
+        let arg_conversions = self.inputs.iter()
+            .filter_map(|arg| {
+                let name = &arg.name;
+                let conversion = arg.ty.convert_java_to_rust(&name.to_token_stream())?;
+                Some(quote_spanned! {arg.span()=> let #name = #conversion; })
+            });
+        // The return value is converted to a Java value.
+        // The body is put in a closure so that it can still use `return` (and `?`).
         let content = &self.content;
+        let conversion = self.output.convert_rust_to_java(&quote!(__result))?;
+        let (catch_throw, content) = match (&self.output.0, conversion) {
+            // The Ok value is converted, and the Err value will be thrown as an Exception
+            (Return::Result { err_ty, .. }, conversion) => (quote!(catch_throw_result), {
+                let conversion = conversion.map(|conversion| quote!(.map(|__result| #conversion)));
+                quote! {
+                    let __result: ::std::result::Result<_, #err_ty> = (|| { #content })();
+                    __result #conversion
+                }
+            }),
+            (Return::Assertive(_), Some(conversion)) => (quote!(catch_throw), quote! {
+                let __result = (|| { #content })();
+                #conversion
+            }),
+            (Return::Assertive(_), None) => (quote!(catch_throw), content.clone()),
+        };
+        // Static methods receive the Class, and instance methods receive the Object the method was called on
+        let receiver = match &self.receiver {
+            Some(receiver) => quote_spanned! {receiver.span()=> #[allow(unused_variables)] this: ::jni::objects::JObject<'local>},
+            None => quote!(_class: ::jni::objects::JClass<'local>),
+        };
 
-        syn::parse_quote! {
+        Ok(syn::parse_quote! {
             #(#attrs)*
             #[doc = ""]
             #[doc = #method_sig]
             #[no_mangle]
-            pub extern "system" fn #name<#lifetime>(mut env: ::jni::JNIEnv<'local>, _class: ::jni::objects::JClass<'local>, #inputs) #output {
-                ::ez_jni::__throw::catch_throw(&mut env, move |env| { #content })
+            pub extern "system" fn #name<#lifetime>(mut env: ::jni::JNIEnv<'local>, #receiver, #inputs) #output {
+                ::ez_jni::__throw::#catch_throw(&mut env, move |env| {
+                    #(#arg_conversions)*
+                    #content
+                })
             }
-        }
+        })
     }
 }
 impl Parse for JniFn {
@@ -112,13 +147,24 @@ impl Parse for JniFn {
             .ok();
 
         // Parse arguments with Java types
-        let inputs = {
+        let (receiver, inputs) = {
             let inner;
             parenthesized!(inner in input);
 
-            Punctuated::<JniFnArg, Token![,]>::parse_terminated(&inner)
+            // Parse the `self` receiver, which makes the function an instance method
+            let receiver = if inner.peek(Token![self]) {
+                let receiver = inner.parse::<Token![self]>()?;
+                if !inner.is_empty() {
+                    inner.parse::<Token![,]>()?;
+                }
+                Some(receiver)
+            } else {
+                None
+            };
+
+            (receiver, Punctuated::<JniFnArg, Token![,]>::parse_terminated(&inner)
                 .map_err(|err| errors.push(err))
-                .ok()
+                .ok())
         };
 
         // Parse return arrow `->` and return type (is void if there is none)
@@ -175,8 +221,8 @@ impl Parse for JniFn {
             errors.push(syn::Error::new(lifetime.span(), LIFETIME_ERROR))
         }
 
-        // Check that the function doesn't have arguments named "env" or "_class" because those are implicitly added
-        for arg_name in ["env", "_class"] {
+        // Check that the function doesn't have arguments named "env", "_class", or "this" because those are implicitly added
+        for arg_name in ["env", "_class", "this"] {
             if let Some(arg) = inputs.iter().find(|arg| arg.name.to_string() == arg_name) {
                 errors.push(syn::Error::new(
                     arg.span(),
@@ -190,30 +236,42 @@ impl Parse for JniFn {
         let class = class.unwrap();
         let lifetime = lifetime.unwrap();
 
-        Ok(Self { attrs, class, name, lifetime, inputs, output, content })
+        Ok(Self { attrs, class, name, lifetime, receiver, inputs, output, content })
     }
 }
 impl ToTokens for JniFn {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.append_all(self.to_rust_fn().into_token_stream())
+        match self.to_rust_fn() {
+            Ok(f) => tokens.append_all(f.into_token_stream()),
+            Err(err) => tokens.append_all(err.into_compile_error()),
+        }
     }
 }
 
 pub struct JniFnArg {
     pub attrs: Vec<Attribute>,
     pub name: Ident,
-    pub ty: InnerType
+    pub ty: JniFnArgType
 }
 impl Parse for JniFnArg {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
-            attrs: Attribute::parse_outer(input)?,
-            name: input.parse()?,
-            ty: {
-                input.parse::<Token![:]>()?;
-                input.parse()?
+        let mut attrs = Attribute::parse_outer(input)?;
+        let class = take_class_attribute(&mut attrs)?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+
+        let ty = match class {
+            // The argument has a Rust type that is converted from an Object of the Class
+            Some(class) => JniFnArgType::Rust { class, ty: input.parse()? },
+            None => match input.parse()? {
+                ReturnableType::Void(ident) => return Err(syn::Error::new(ident.span(), "Arguments can't be 'void'")),
+                ReturnableType::Rust(ty)
+                | ReturnableType::Option(OptionType::Rust(ty)) => return Err(syn::Error::new(ty.span(), "Arguments with a Rust type must have the 'class' attribute, e.g. `#[class(me.author.MyClass)] arg: MyClass`")),
+                ty => JniFnArgType::Java(ty),
             }
-        })
+        };
+
+        Ok(Self { attrs, name, ty })
     }
 }
 impl ToTokens for JniFnArg {
@@ -222,66 +280,181 @@ impl ToTokens for JniFnArg {
         tokens.append(self.name.clone());
         tokens.append_all(quote!(:));
         
-        // Convert Class to JObject (java.lang.String to JString) and leave primitives alone
+        // The raw JNI type of the argument, which is converted to the Rust type in the function's body
         tokens.append_all(match &self.ty {
-            InnerType::RustPrimitive { ident, ty } => Ident::new(&ty.to_string(), ident.span()).into_token_stream(),
-            InnerType::JavaPrimitive { ident, ty } => Ident::new(&RustPrimitive::from(*ty).to_string(), ident.span()).into_token_stream(),
-            InnerType::Object(class) =>
-                if class.to_string() == "java.lang.String" {
-                    quote_spanned! {class.span()=> ::jni::objects::JString<'local>}
-                } else {
-                    quote_spanned! {class.span()=> ::jni::objects::JObject<'local>}
-                }
+            JniFnArgType::Java(ty) => raw_jni_type(ty, quote!(::jni::objects::JObject<'local>)),
+            JniFnArgType::Rust { class, .. } => quote_spanned! {class.span()=> ::jni::objects::JObject<'local>},
         })
     }
 }
 
-pub enum JniReturn {
-    Void,
-    Type(InnerType)
+/// The type of a [`JniFnArg`].
+pub enum JniFnArgType {
+    /// A **Java** type, which is passed to the function as the Rust type that [`call!`][crate::call!] would return for that type.
+    /// e.g. `java.lang.String` is converted to [`String`], `Option<java.lang.String>` to `Option<String>`, `[int]` to `Box<[i32]>`.
+    /// Other Classes are passed as [`JObject`](jni::objects::JObject).
+    Java(ReturnableType),
+    /// A **Rust** type that implements `FromObject`,
+    /// with the Java **Class** (from the `class` attribute) of the Object it is converted from.
+    Rust { class: ClassPath, ty: Box<syn::Type> },
 }
-impl SigType for JniReturn {
+impl SigType for JniFnArgType {
     fn sig_char(&self) -> Ident {
         match self {
-            Self::Void => Ident::new("v", Span::call_site()),
-            Self::Type(ty) => ty.sig_char()
+            Self::Java(ty) => ty.sig_char(),
+            Self::Rust { class, .. } => class.sig_char(),
         }
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         match self {
-            Self::Void => LitStr::new("V", Span::call_site()),
-            Self::Type(ty) => ty.sig_type()
+            Self::Java(ty) => ty.sig_type(),
+            Self::Rust { class, .. } => class.sig_type(),
         }
     }
 }
+impl SpecialCaseConversion for JniFnArgType {
+    /// Returns code that converts the *raw JNI* argument to its Rust type.
+    /// 
+    /// Like with `call!`, *Objects* that are not wrapped in `Option` will cause a `panic!` if they are **null**.
+    fn convert_java_to_rust(&self, value: &TokenStream) -> Option<TokenStream> {
+        match self {
+            Self::Java(ReturnableType::Void(_)) => None,
+            Self::Java(ReturnableType::Rust(_) | ReturnableType::Option(OptionType::Rust(_))) => panic!("Unreachable code; JniFnArg::parse() denies Rust types without a class"),
+            // jni::sys::jboolean (u8) must be converted to rust bool
+            Self::Java(ReturnableType::Assertive(InnerType::RustPrimitive { ty: RustPrimitive::Bool, .. }
+                | InnerType::JavaPrimitive { ty: JavaPrimitive::Boolean, .. })
+            ) => Some(quote_spanned! {value.span()=> #value != 0 }),
+            Self::Java(ty @ ReturnableType::Assertive(InnerType::RustPrimitive { .. } | InnerType::JavaPrimitive { .. })) =>
+                ty.special_case_conversions(value.clone()),
+            Self::Java(ty @ (ReturnableType::Assertive(InnerType::Object(_) | InnerType::Array(_)) | ReturnableType::Array(_))) => {
+                let null_err = format!("Argument \"{value}\" was null. If this is intended, wrap its type with 'Option'");
+                let conversion = ty.special_case_conversions(value.clone())
+                    .unwrap_or_else(|| value.clone());
+                Some(quote_spanned! {value.span()=> {
+                    if #value.is_null() {
+                        panic!(#null_err)
+                    }
+                    #conversion
+                } })
+            },
+            Self::Java(ty @ ReturnableType::Option(_)) => Some(match ty.special_case_conversions(value.clone()) {
+                Some(conversion) => quote_spanned! {value.span()=> (!#value.is_null()).then(|| #conversion) },
+                None => quote_spanned! {value.span()=> (!#value.is_null()).then_some(#value) },
+            }),
+            Self::Rust { ty, .. } => {
+                let error = format!("Failed to convert argument \"{value}\" to {}: {{err}}", ty.to_token_stream());
+                Some(quote_spanned! {value.span()=> {
+                    use ::std::borrow::BorrowMut as _;
+                    <#ty as ::ez_jni::FromObject>::from_object(&#value, env.borrow_mut())
+                        .unwrap_or_else(|err| panic!(#error))
+                } })
+            },
+        }
+    }
+    fn convert_rust_to_java(&self, _value: &TokenStream) -> Option<TokenStream> {
+        None
+    }
+}
+/// The return type of a [`JniFn`].
+/// Is **void** if the function has no return type.
+/// 
+/// Can be a [`Result`], in which case the `Err` value will be *thrown* as a Java Exception.
+pub struct JniReturn(Return);
+impl SigType for JniReturn {
+    fn sig_char(&self) -> Ident {
+        self.0.sig_char()
+    }
+    fn sig_type(&self) -> Signature {
+        self.0.sig_type()
+    }
+}
+impl JniReturn {
+    /// Returns code that converts the value returned by the function's body to the *raw JNI* type.
+    /// 
+    /// Any *Object* (or `Option` of an Object) can be returned by the body as a type that implements `ToObject`.
+    /// *Arrays* can be returned as anything that is `AsRef<[T]>`.
+    fn convert_rust_to_java(&self, value: &TokenStream) -> syn::Result<Option<TokenStream>> {
+        Ok(match self.0.inner() {
+            ReturnableType::Void(_) => None,
+            ReturnableType::Rust(_) | ReturnableType::Option(OptionType::Rust(_)) => panic!("Unreachable code; JniReturn::parse() denies Rust types"),
+            ReturnableType::Assertive(ty @ (InnerType::RustPrimitive { .. } | InnerType::JavaPrimitive { .. })) =>
+                ty.convert_rust_to_java(value),
+            ReturnableType::Assertive(InnerType::Object(_) | InnerType::Array(_))
+            | ReturnableType::Option(OptionType::Object(_)) => Some(quote_spanned! {value.span()=> {
+                use ::std::borrow::BorrowMut as _;
+                ::ez_jni::ToObject::to_object(&#value, env.borrow_mut()).into_raw()
+            } }),
+            ReturnableType::Array(array) => Some(array_to_java(array, value)?),
+            ReturnableType::Option(OptionType::Array(array)) => {
+                let conversion = array_to_java(array, &quote_spanned!(value.span()=> __array))?;
+                Some(quote_spanned! {value.span()=>
+                    match #value {
+                        ::std::option::Option::Some(__array) => #conversion,
+                        ::std::option::Option::None => ::std::ptr::null_mut(),
+                    }
+                })
+            },
+        })
+    }
+}
 impl Parse for JniReturn {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         // Parse return arrow `->` and return type (is void if there is none)
-        Ok(if input.parse::<Token![->]>().is_ok() {
-            Self::Type(input.parse()?)
+        let output = if input.parse::<Token![->]>().is_ok() {
+            input.parse::<Return>()?
         } else {
-            Self::Void
-        })
+            Return::new_void(Span::call_site())
+        };
+
+        match output.inner() {
+            ReturnableType::Rust(ty)
+            | ReturnableType::Option(OptionType::Rust(ty)) => Err(syn::Error::new(ty.span(), "The return type can't be a Rust type; use the Java Class instead, and return any value that implements 'ToObject'.")),
+            _ => Ok(Self(output)),
+        }
     }
 }
 impl ToTokens for JniReturn {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        match self {
-            Self::Void => { }, // Void return has no tokens
-            Self::Type(output) => {
-                // Convert Class to *jobject (or *jstring) and leave primitives alone
-                let ty = match output {
-                    InnerType::RustPrimitive { ident, ty } => Ident::new(&ty.to_string(), ident.span()).into_token_stream(),
-                    InnerType::JavaPrimitive { ident, ty } => Ident::new(&RustPrimitive::from(*ty).to_string(), ident.span()).into_token_stream(),
-                    InnerType::Object(class) =>
-                        if class.to_string() == "java.lang.String" {
-                            quote_spanned! {class.span()=> ::jni::sys::jstring}
-                        } else {
-                            quote_spanned! {class.span()=> ::jni::sys::jobject}
-                        }
-                };
+        match self.0.inner() {
+            ReturnableType::Void(_) => { }, // Void return has no tokens
+            output => {
+                let ty = raw_jni_type(output, quote!(::jni::sys::jobject));
                 tokens.append_all(quote_spanned!(output.span()=> -> #ty))
             },
         }
     }
+}
+
+/// Get the *raw JNI* type that a [`ReturnableType`] has in the signature of the exported function.
+/// 
+/// Primitives use their `jni::sys` type (e.g. `jint`), and all Objects (including Arrays) use **object**.
+fn raw_jni_type(ty: &ReturnableType, object: TokenStream) -> TokenStream {
+    let prim = match ty {
+        ReturnableType::Assertive(InnerType::JavaPrimitive { ty, .. }) => *ty,
+        ReturnableType::Assertive(InnerType::RustPrimitive { ty, .. }) => JavaPrimitive::from(*ty),
+        _ => return quote_spanned!(ty.span()=> #object),
+    };
+    let ident = Ident::new(&format!("j{prim}"), ty.span());
+    quote_spanned!(ty.span()=> ::jni::sys::#ident)
+}
+
+/// Returns code that converts a Rust *slice* (anything that is `AsRef<[T]>`) to a raw *Java Array*.
+/// 
+/// Object arrays (and arrays with *nullable* elements) use `ToObject` to convert each element, like [`JniReturn`] does for Objects.
+fn array_to_java(array: &ReturnArray, value: &TokenStream) -> syn::Result<TokenStream> {
+    Ok(match array {
+        ReturnArray::Assertive(ty @ InnerType::Object(_))
+        | ReturnArray::Option(ty) => {
+            let class = ty.element_class_path();
+            quote_spanned! {value.span()=> {
+                use ::std::borrow::BorrowMut as _;
+                ::ez_jni::utils::__create_object_array(::std::convert::AsRef::<[_]>::as_ref(&#value), #class, env.borrow_mut()).into_raw()
+            } }
+        },
+        _ => {
+            let conversion = array.to_array_type().convert_rust_to_java(value)
+                .ok_or_else(|| syn::Error::new(array.span(), "Can't convert the returned value to a Java Array of this type"))?;
+            quote_spanned!(value.span()=> #conversion.into_raw())
+        }
+    })
 }
\ No newline at end of file
@@ -1,10 +1,12 @@
 mod jni_fn;
 mod call;
+mod field;
 mod object;
 mod utils;
 mod types;
 
 use call::{ConstructorCall, MethodCall};
+use field::FieldAccess;
 use either::Either;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
@@ -14,6 +16,28 @@ use utils::item_from_derive_input;
 /// 
 /// The *arguments* and *return type* must be **Java Types** (or Rust primitives like u8).
 /// 
+/// They can also be **Arrays** of those types (the type wrapped in *brackets* `[]`), with the same syntax as in [`call!`].
+/// 
+/// ### Conversions
+/// 
+/// The *arguments* are converted to Rust values the same way that [`call!`] converts its *return value*:
+/// `java.lang.String` (or just `String`) is converted to [`String`],
+/// Arrays are converted to a Rust [boxed slice](Box) (e.g. `[int]` to `Box<[i32]>`, and `[[int]]` to `Box<[Box<[i32]>]>`),
+/// and any other Class is passed as a [`JObject`](jni::objects::JObject).
+/// Wrap an Object type with [`Option`] if the argument can be **null** (e.g. `Option<String>`),
+/// otherwise the function will `panic!` if it receives **null**.
+/// 
+/// **Breaking change:** Object arguments that are *not* wrapped in [`Option`] (e.g. `me.author.MyClass`)
+/// used to be passed to the function as a **null** [`JObject`](jni::objects::JObject).
+/// They now cause a `panic!` (which is thrown as a Java Exception), so wrap their types in [`Option`] if they can be **null**.
+/// 
+/// An argument can also have a **Rust type** that implements [`FromObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.FromObject.html)
+/// if the argument has a `class` attribute with the Class of the Java Object it is converted from
+/// (e.g. `#[class(me.author.MyClass)] arg: MyStruct`).
+/// 
+/// When the *return type* is an Object (or an [`Option`] of an Object), the function can return any type that implements [`ToObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToObject.html).
+/// When the *return type* is an Array, the function can return any type that is `AsRef<[T]>` (e.g. [`Vec`]).
+/// 
 /// You can also do multiple function definitions in one macro call.
 /// 
 /// ### Requirements
@@ -22,18 +46,60 @@ use utils::item_from_derive_input;
 /// 1. be defined with `pub` visibility,
 /// 2. have exactly *one lifetime* (named `local`),
 /// 3. no generic constants or types,
-/// 4. and no arguments named `env` or `_class`.
+/// 4. and no arguments named `env`, `_class`, or `this`.
 ///
 /// The function must also have a `class` attribute with the *full name* of a Java Class (e.g. `java.lang.String`).
 ///
+/// ### Instance methods
+/// 
+/// By default, the function is exported as a `static native` method of the Class.
+/// To export a *non-static* `native` method, put **`self`** as the first argument of the function.
+/// The Java Object that the method was called on will be available in the body of the function as **`this`** (a [`JObject`](jni::objects::JObject)).
+/// 
+/// ```
+/// # use ez_jni::{jni_fn, call};
+/// jni_fn! {
+///     #[class(me.author.MyClass)]
+///     pub fn get_member<'local>(self) -> int {
+///         call!(this.getMember() -> int)
+///     }
+/// }
+/// ```
+///
 /// ### Panic catching
 /// 
 /// The *block* of the function will be wrapped with a *[special function](https://docs.rs/ez_jni/latest/ez_jni/__throw/fn.catch_throw.html)*
 /// that catches `panics!` and throws them as Java `Exception`s with the panic message.
 /// 
+/// The Exception is a `java.lang.RuntimeException` by default,
+/// but the Class can be changed with [`set_panic_exception_class()`](https://docs.rs/ez_jni/latest/ez_jni/fn.set_panic_exception_class.html)
+/// (e.g. so that Java code can distinguish Rust panics from other errors).
+/// 
 /// When a panic is caught and the exception is *thrown*,
 /// the function will return a *[zeroed](std::mem::zeroed)* representation of the return type.
 /// 
+/// The first time a `jni_fn` is called, a *[panic hook](std::panic::set_hook)* is installed that collects the panic location and backtrace.
+/// This hook calls the hook that was set before it, so any hooks set by the user (e.g. for logging) still run when a `jni_fn` panics.
+/// Hooks must therefore be set *before* calling any `jni_fn`, otherwise they will replace the hook installed by this crate.
+/// 
+/// ### Throwing Exceptions
+/// 
+/// The *return type* can also be a [`Result<T, E>`], where `T` is any of the types above (or `void`),
+/// and `E` is a Rust type that *implements [`ToException`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToException.html)*.
+/// When the function returns `Err`, the error will be converted to a *Java Exception* and **thrown**,
+/// and the function will return a *[zeroed](std::mem::zeroed)* representation of `T`.
+/// 
+/// ```
+/// # use ez_jni::jni_fn;
+/// jni_fn! {
+///     #[class(me.author.MyClass)]
+///     pub fn parse_int<'local>(s: String) -> Result<int, String> {
+///         s.parse::<i32>()
+///             .map_err(|err| err.to_string())
+///     }
+/// }
+/// ```
+/// 
 /// ### Mark of the sig
 /// 
 /// A doc-comment will be appended to the function definition with the *Java method signature* that must be used to call the function.
@@ -45,7 +111,7 @@ use utils::item_from_derive_input;
 /// jni_fn! {
 ///     #[class(me.author.MyClass)]
 ///     pub fn hello_world<'local>(s: java.lang.String) -> int {
-///         3
+///         s.len() as i32
 ///     }
 /// }
 /// ```
@@ -56,10 +122,19 @@ use utils::item_from_derive_input;
 /// #[no_mangle]
 /// pub extern "system" fn Java_me_author_MyClass_hello_1world<'local>(
 ///     mut env: ::jni::JNIEnv<'local>, _class: ::jni::objects::JClass<'local>,
-///     s: ::jni::objects::JString<'local>,
-/// ) -> i32 {
+///     s: ::jni::objects::JObject<'local>,
+/// ) -> ::jni::sys::jint {
 ///     ::ez_jni::__throw::catch_throw(&mut env, move |env| {
-///         3
+///         let s = {
+///             if s.is_null() {
+///                 panic!("Argument \"s\" was null. If this is intended, wrap its type with 'Option'")
+///             }
+///             {
+///                 use ::std::borrow::BorrowMut as _;
+///                 ::ez_jni::utils::get_string(::jni::objects::JString::from(s), env.borrow_mut())
+///             }
+///         };
+///         s.len() as i32
 ///     })
 /// }
 /// ```
@@ -101,6 +176,27 @@ pub fn jni_fn(input: TokenStream) -> TokenStream {
 /// call!((getObject()).myMethod() -> void);
 /// ```
 /// 
+/// The object in parentheses can be *cast* to a Class or Interface with `as` followed by the *fully-qualified class name*.
+/// This checks that the Object is an instance of that Class before calling the method,
+/// and will `panic!` with a message naming both Classes if it is not.
+/// ```ignore
+/// call!((my_list as java.util.List).size() -> int);
+/// ```
+/// 
+/// To call the implementation of the method of a specific Class (e.g. the *superclass*) instead of the Object's own implementation,
+/// put `super` and the *fully-qualified class name* in parentheses before the *object*.
+/// This is the equivalent of `super.myMethod()` in Java, and has the same syntax for the arguments and return type as any other call.
+/// ```ignore
+/// call!(super(me.author.BaseClass) my_object.myMethod() -> int);
+/// ```
+/// 
+/// The *Class* and *Method ID* are looked up the first time the call is made, and are cached for subsequent calls of the same `call!`
+/// (this is also the case for [`new!`]).
+/// For *object methods*, the ID is looked up again if the Object is not an instance of any of the Classes IDs were cached for,
+/// and IDs for a few different Classes are kept so that calls with Objects of different Classes don't replace each other's IDs.
+/// IDs are also looked up again if the *signature* is different (e.g. for a Rust type in a generic function),
+/// so a call is never made with the ID of another overload.
+/// 
 /// ## Types
 /// 
 /// The function call has *argument* and *return* **types**.
@@ -109,11 +205,32 @@ pub fn jni_fn(input: TokenStream) -> TokenStream {
 /// a **[Rust Primitive](std::primitive)**,
 /// a **Java Class**,
 /// or an **Array** of one of the previous types (the type wrapped in *brackets* `[]`).
+/// The type of an Array can also be another Array, for *multi-dimensional* Arrays (e.g. `[[int]]` for `int[][]`).
 /// 
 /// For the class `java.lang.String`, use the Rust type [`String`] instead.
 /// The values will be automatically converted by the macro between Rust and Java
 /// (depending on whether the type is for an *argument* or *return*).
 /// 
+/// ### Rust types
+/// 
+/// A type can also be a **Rust type** (e.g. `MyPoint`, `crate::MyPoint`, or `Vec<MyPoint>`)
+/// that implements [`JavaClass`](https://docs.rs/ez_jni/latest/ez_jni/trait.JavaClass.html),
+/// which provides the type's *signature*.
+/// Arguments are converted to Java with [`ToObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToObject.html),
+/// and return values are converted to Rust with [`FromObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.FromObject.html).
+/// 
+/// Any path that is not a *primitive*, `String`, or a *Java Class* (which always has a package, e.g. `me.author.MyPoint`) is a Rust type.
+/// Rust types can't be the element of an Array type; use a Rust collection instead (e.g. `Vec<MyPoint>` instead of `[MyPoint]`).
+/// The signature of a Rust type must be of a Class or Array, so the call will `panic!` if it is of a *primitive*
+/// (e.g. for an alias like `type Id = i32;`); use the Java primitive type (e.g. `int`) instead.
+/// The exceptions are `Checked` and `Widened` of an unsigned integer, which are converted to and from a *primitive*
+/// (e.g. `Checked<u32>` to `int`, and `Widened<u32>` to `long`), so they can't be wrapped in [`Option`].
+/// ```ignore
+/// call!(my_object.getPoint() -> MyPoint);
+/// call!(my_object.setPoint(MyPoint(my_point)) -> void);
+/// call!(my_object.getPoints() -> Vec<MyPoint>);
+/// ```
+/// 
 /// In the sections below, the use of `T` or `Type` means that it can accept any of the types declared above.
 ///
 /// ## Arguments
@@ -142,13 +259,25 @@ pub fn jni_fn(input: TokenStream) -> TokenStream {
 /// [java.lang.Object](null)     // object array (with null)
 /// [String](["Hello", "World"]) // string array
 /// [String](["Hello", null])    // string array (with null)
+/// [[int]]([[1, 2], [3, 4]])    // multi-dimensional array
+/// MyPoint(my_point)            // Rust type
+/// ```
+///
+/// The **last** argument can be a *varargs* argument (e.g. `Object... args` in Java),
+/// written as the *element type* followed by `...` and the *comma-separated values* in parenthesis.
+/// The values are packed into an Array of that type.
+/// For *Objects*, each value can be `null` or any Rust value that *implements [`ToObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToObject.html)*,
+/// so values of different types can be mixed (e.g. numbers are converted to their *boxed* Java Class).
+/// ```ignore
+/// call!(static java.lang.String.format(String("%s: %d"), java.lang.Object...("Count", 3)) -> String);
+/// call!(static me.author.ClassName.sum(int...(1, 2, 3)) -> int);
 /// ```
 ///
 /// ## Return
 ///
 /// The arguments are followed by a *return arrow* `->` and the **return type**.
 /// The return type may be *void*, one of the [`Types`](https://docs.rs/ez_jni/latest/ez_jni/macro.call.html#types) above,
-/// an [`Option`] of a Class, or a [`Result<T, E>`] of any of the previous choices.
+/// an [`Option`] of a Class (or of a Rust type), or a [`Result<T, E>`] of any of the previous choices.
 ///
 /// - Use the **assertive type** (`T` by itself) when the Java method being called *can't return `NULL`* or throw an *exception*,
 ///   such as when it is marked with `@NonNull`.
@@ -162,7 +291,10 @@ pub fn jni_fn(input: TokenStream) -> TokenStream {
 /// -> java.lang.String
 /// -> [int]
 /// -> [String]
+/// -> [[int]]
+/// -> [Option<[String]>]
 /// -> Option<java.lang.String>
+/// -> Option<MyPoint>
 /// -> Result<int, String>
 /// -> Result<Option<String>, MyErrorType>
 /// ```
@@ -179,6 +311,10 @@ pub fn jni_fn(input: TokenStream) -> TokenStream {
 /// This is similar to how in Java, if the exception is not of any type of the *catch blocks*, the exception will not be caught.
 ///
 /// When `E` is [`String`], it will catch any Exception.
+/// 
+/// When an Exception is not caught (either because the return type is not a [`Result`] or because the conversion failed),
+/// it is rethrown with a *suppressed* Exception that describes where in the Rust code the Method was called,
+/// so that the Java stack trace shows which `call!` the Exception went through.
 #[proc_macro]
 pub fn call(input: TokenStream) -> TokenStream {
     let call = syn::parse_macro_input!(input as MethodCall);
@@ -208,6 +344,38 @@ pub fn new(input: TokenStream) -> TokenStream {
     call::jni_call_constructor(call).into()
 }
 
+/// Read or write a *field* (a.k.a. *member*) of a Java Object, or a *static field* of a Class.
+/// 
+/// Has similar syntax as [*calling a method*][crate::call!],
+/// but instead of the *arguments* the field name is followed by either:
+/// - a *return arrow* `->` and the **type** of the field, to **read** the field's value.
+///   The type can be any of the [*return types*](crate::call!#return) of `call!`, except for `void` and `Result`.
+/// - an *equals sign* `=` and a **value** with the same syntax as an [*argument*](crate::call!#arguments) of `call!`, to **write** to the field.
+/// 
+/// ```ignore
+/// let value = field!(my_object.memberField -> int);
+/// field!(my_object.memberField = int(5));
+/// field!((getObject()).message = String("Hello, World!"));
+/// ```
+/// 
+/// If the Object does not have a field with that name, the *getter* or *setter method* for it is called instead
+/// (e.g. `getMemberField()` or `setMemberField(5)`).
+/// The macro will `panic!` if neither the field nor the method exist.
+/// 
+/// To access a **static field**, start with the `static` keyword, then the *fully-qualified class name*, and the *field name*.
+/// Static fields do not fall back to *getter* or *setter methods*.
+/// 
+/// ```ignore
+/// let max = field!(static java.lang.Integer.MAX_VALUE -> int);
+/// let out = field!(static java.lang.System.out -> java.io.PrintStream);
+/// field!(static me.author.ClassName.instance = me.author.ClassName(null));
+/// ```
+#[proc_macro]
+pub fn field(input: TokenStream) -> TokenStream {
+    let access = syn::parse_macro_input!(input as FieldAccess);
+    field::jni_field(access).into()
+}
+
 /// See [`ez_jni::FromObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.FromObject.html).
 #[proc_macro_derive(FromObject, attributes(class, field))]
 pub fn from_object(input: TokenStream) -> TokenStream {
@@ -220,6 +388,27 @@ pub fn from_object(input: TokenStream) -> TokenStream {
     }.into()
 }
 
+/// See [`ez_jni::ToObject`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToObject.html).
+#[proc_macro_derive(ToObject, attributes(class, field, constructor))]
+pub fn to_object(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match item_from_derive_input(input) {
+        Either::Left(st) => object::to_object_st(st)
+            .unwrap_or_else(|err| err.to_compile_error()),
+        Either::Right(enm) => object::to_object_enum(enm)
+            .unwrap_or_else(|err| err.to_compile_error()),
+    }.into()
+}
+
+/// See [`ez_jni::JavaClass`](https://docs.rs/ez_jni/latest/ez_jni/trait.JavaClass.html).
+#[proc_macro_derive(JavaClass, attributes(class))]
+pub fn java_class(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    object::java_class(item_from_derive_input(input))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// See [`ez_jni::FromException`](https://docs.rs/ez_jni/latest/ez_jni/trait.FromException.html).
 #[proc_macro_derive(FromException, attributes(class, field))]
 pub fn from_exception(input: TokenStream) -> TokenStream {
@@ -232,6 +421,18 @@ pub fn from_exception(input: TokenStream) -> TokenStream {
     }.into()
 }
 
+/// See [`ez_jni::ToException`](https://docs.rs/ez_jni/latest/ez_jni/trait.ToException.html).
+#[proc_macro_derive(ToException, attributes(class, cause))]
+pub fn to_exception(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match item_from_derive_input(input) {
+        Either::Left(st) => object::to_exception_struct(st)
+            .unwrap_or_else(|err| err.to_compile_error()),
+        Either::Right(enm) => object::to_exception_enum(enm)
+            .unwrap_or_else(|err| err.to_compile_error()),
+    }.into()
+}
+
 /// Print output. See [`std::println!`](https://doc.rust-lang.org/std/macro.println.html).
 /// 
 /// In Android, printing to `STDOUT` does not work because apparently it redirects to `/dev/null`.
@@ -16,20 +16,17 @@ pub fn from_object_st(mut st: ItemStruct) -> syn::Result<TokenStream> {
 
     Ok(quote! {
         impl <#st_generic_params> ::ez_jni::FromObject<#env_lt> for #st_ident #st_generics {
+            const CLASS: Option<&'static str> = Some(#class);
+
             fn from_object(object: &::jni::objects::JObject, env: &mut ::jni::JNIEnv<#env_lt>) -> Result<Self, ::ez_jni::FromObjectError> {
                 if object.is_null() {
                     return Err(::ez_jni::FromObjectError::Null);
                 }
 
-                static __CLASS: &str = #class;
-
-                let __class = env.get_object_class(object)
-                    .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-                
-                if !env.is_instance_of(object, __CLASS).unwrap() {
+                if !env.is_instance_of(object, #class).unwrap() {
                     return Err(::ez_jni::FromObjectError::ClassMismatch {
-                        obj_class: ::ez_jni::call!(__class.getName() -> String),
-                        target_class: Some(__CLASS.to_string())
+                        obj_class: ::ez_jni::utils::get_object_class_name(object, env),
+                        target_class: Some(#class.to_string())
                     })
                 }
 
@@ -52,14 +49,12 @@ pub fn from_object_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
         .and_then(|o| o)
         .map(|class| class.to_jni_class_path());
 
+    let class_const = class_const(base_class.as_deref());
     let base_class_check = base_class.map(|class| quote_spanned! {enm.ident.span()=>
-        static __BASE_CLASS: &str = #class;
-        let __class = env.get_object_class(object)
-            .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-        if !env.is_instance_of(object, __BASE_CLASS).unwrap() {
+        if !env.is_instance_of(object, #class).unwrap() {
             return Err(::ez_jni::FromObjectError::ClassMismatch {
-                obj_class: ::ez_jni::call!(__class.getName() -> String),
-                target_class: Some(__BASE_CLASS.to_string())
+                obj_class: ::ez_jni::utils::get_object_class_name(object, env),
+                target_class: Some(#class.to_string())
             })
         }
     }).unwrap_or(TokenStream::new());
@@ -76,6 +71,8 @@ pub fn from_object_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
     let enm_generics = &enm.generics;
     Ok(quote! {
         impl <#enm_generic_params> ::ez_jni::FromObject<#env_lt> for #enm_ident #enm_generics {
+            #class_const
+
             fn from_object(object: &::jni::objects::JObject, env: &mut ::jni::JNIEnv<#env_lt>) -> Result<Self, ::ez_jni::FromObjectError> {
                 if object.is_null() {
                     return Err(::ez_jni::FromObjectError::Null);
@@ -84,11 +81,146 @@ pub fn from_object_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
 
                 #(#class_checks)* else {
                     Err(::ez_jni::FromObjectError::ClassMismatch {
-                        obj_class: ::ez_jni::call!(__class.getName() -> String),
+                        obj_class: ::ez_jni::utils::get_object_class_name(object, env),
                         target_class: None
                     })
                 }
             }
         }
     })
-}
\ No newline at end of file
+}
+pub fn to_object_st(mut st: ItemStruct) -> syn::Result<TokenStream> {
+    let class = take_class_attribute_required(&mut st.attrs, st.ident.span())?;
+    let class_path = class.to_jni_class_path();
+    let use_ctor = take_constructor_attribute(&mut st.attrs)?;
+
+    // Read the fields of the struct with `self`
+    let accessors = st.fields.iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => quote_spanned!(field.span()=> self.#ident),
+            None => {
+                let index = syn::Index::from(i);
+                quote_spanned!(field.span()=> self.#index)
+            }
+        })
+        .collect::<Box<[_]>>();
+    let object = object_from_fields(class, &st.fields, &accessors, use_ctor)?;
+
+    let mut st_generic_params = st.generics.params.clone();
+    let env_lt = get_local_lifetime(Either::Left(&st), &mut st_generic_params);
+    let st_ident = st.ident;
+    let st_generics = st.generics;
+
+    Ok(quote! {
+        impl <#st_generic_params> ::ez_jni::ToObject<#env_lt> for #st_ident #st_generics {
+            const CLASS: Option<&'static str> = Some(#class_path);
+
+            fn to_object(&self, env: &mut ::jni::JNIEnv<#env_lt>) -> ::jni::objects::JObject<#env_lt> {
+                #object
+            }
+        }
+    })
+}
+
+pub fn to_object_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
+    let mut errors = Vec::new();
+
+    if enm.variants.is_empty() {
+        errors.push(syn::Error::new(Span::call_site(), "Enum must have at least 1 variant"));
+    }
+
+    // The base class is not needed to create the Object, but it is the element Class of Arrays of the enum
+    let base_class = take_class_attribute(&mut enm.attrs)
+        .map_err(|err| errors.push(err))
+        .ok()
+        .and_then(|o| o)
+        .map(|class| class.to_jni_class_path());
+    let class_const = class_const(base_class.as_deref());
+
+    let arms = enm.variants.iter_mut()
+        .map(|variant| {
+            let class = take_class_attribute_required(&mut variant.attrs, variant.ident.span())?;
+            let use_ctor = take_constructor_attribute(&mut variant.attrs)?;
+
+            // Bind the fields of the variant to variables, and read the fields from those variables
+            let bindings = (0..variant.fields.len())
+                .map(|i| Ident::new(&format!("__field_{i}"), Span::call_site()))
+                .collect::<Box<[_]>>();
+            let accessors = bindings.iter()
+                .map(|binding| quote!((*#binding)))
+                .collect::<Box<[_]>>();
+            let object = object_from_fields(class, &variant.fields, &accessors, use_ctor)?;
+
+            let ident = &variant.ident;
+            let pattern = match &variant.fields {
+                Fields::Unit => quote!(),
+                Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+                Fields::Named(syn::FieldsNamed { named, .. }) => {
+                    let names = named.iter()
+                        .map(|field| field.ident.as_ref().unwrap());
+                    quote!({ #(#names: #bindings),* })
+                }
+            };
+
+            Ok(quote_spanned! {variant.span()=>
+                Self::#ident #pattern => #object,
+            })
+        })
+        .filter_map(|res: syn::Result<_>| res.map_err(|err| errors.push(err)).ok())
+        .collect::<Box<[_]>>();
+
+    merge_errors(errors)?;
+
+    let mut enm_generic_params = enm.generics.params.clone();
+    let env_lt = get_local_lifetime(Either::Right(&enm), &mut enm_generic_params);
+    let enm_ident = enm.ident;
+    let enm_generics = &enm.generics;
+    Ok(quote! {
+        impl <#enm_generic_params> ::ez_jni::ToObject<#env_lt> for #enm_ident #enm_generics {
+            #class_const
+
+            fn to_object(&self, env: &mut ::jni::JNIEnv<#env_lt>) -> ::jni::objects::JObject<#env_lt> {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+pub fn java_class(item: Either<ItemStruct, ItemEnum>) -> syn::Result<TokenStream> {
+    let (mut attrs, ident, generics) = match item {
+        Either::Left(st) => (st.attrs, st.ident, st.generics),
+        Either::Right(enm) => (enm.attrs, enm.ident, enm.generics),
+    };
+    // The class of an enum item is the base class of all its variants
+    let class = take_class_attribute_required(&mut attrs, ident.span())?
+        .to_jni_class_path();
+    let signature = format!("L{class};");
+    let array_signature = format!("[{signature}");
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ez_jni::JavaClass for #ident #ty_generics #where_clause {
+            fn class_path() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#class)
+            }
+            fn signature() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#signature)
+            }
+            fn array_signature() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#array_signature)
+            }
+        }
+    })
+}
+
+/// Generates the `CLASS` constant of `FromObject` or `ToObject` for an *enum*,
+/// which is only known if the enum has a **base class**.
+fn class_const(base_class: Option<&str>) -> TokenStream {
+    match base_class {
+        Some(class) => quote! { const CLASS: Option<&'static str> = Some(#class); },
+        None => TokenStream::new(),
+    }
+}
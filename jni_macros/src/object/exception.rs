@@ -20,15 +20,10 @@ pub fn from_exception_struct(mut st: ItemStruct) -> syn::Result<TokenStream> {
             fn from_exception(object: &::jni::objects::JThrowable, env: &mut ::jni::JNIEnv<#env_lt>) -> Result<Self, ::ez_jni::FromObjectError> {
                 // object is guaranteed to not be null by the catch function
 
-                static __CLASS: &str = #class;
-
-                let __class = env.get_object_class(object)
-                    .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-                
-                if !env.is_instance_of(object, __CLASS).unwrap() {
+                if !env.is_instance_of(object, #class).unwrap() {
                     return Err(::ez_jni::FromObjectError::ClassMismatch {
-                        obj_class: ::ez_jni::call!(__class.getName() -> String),
-                        target_class: Some(__CLASS.to_string())
+                        obj_class: ::ez_jni::utils::get_object_class_name(object, env),
+                        target_class: Some(#class.to_string())
                     })
                 }
 
@@ -52,13 +47,10 @@ pub fn from_exception_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
         .map(|class| class.to_jni_class_path());
 
    let base_class_check = base_class.map(|class| quote_spanned! {enm.ident.span()=>
-        static __BASE_CLASS: &str = #class;
-        let __class = env.get_object_class(object)
-            .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-        if !env.is_instance_of(object, __BASE_CLASS).unwrap() {
+        if !env.is_instance_of(object, #class).unwrap() {
             return Err(::ez_jni::FromObjectError::ClassMismatch {
-                obj_class: ::ez_jni::call!(__class.getName() -> String),
-                target_class: Some(__BASE_CLASS.to_string())
+                obj_class: ::ez_jni::utils::get_object_class_name(object, env),
+                target_class: Some(#class.to_string())
             })
         }
     }).unwrap_or(TokenStream::new());
@@ -82,11 +74,160 @@ pub fn from_exception_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
 
                 #(#class_checks)* else {
                     Err(::ez_jni::FromObjectError::ClassMismatch {
-                        obj_class: ::ez_jni::call!(__class.getName() -> String),
+                        obj_class: ::ez_jni::utils::get_object_class_name(object, env),
                         target_class: None
                     })
                 }
             }
         }
     })
-}
\ No newline at end of file
+}
+pub fn to_exception_struct(mut st: ItemStruct) -> syn::Result<TokenStream> {
+    let class = take_class_attribute_required(&mut st.attrs, st.ident.span())?;
+
+    // Read the cause field with `self`
+    let cause = take_cause_attribute(&mut st.fields)?
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => quote_spanned!(field.span()=> self.#ident),
+            None => {
+                let index = syn::Index::from(i);
+                quote_spanned!(field.span()=> self.#index)
+            }
+        });
+    let exception = exception_from_message(class, cause);
+
+    let mut st_generic_params = st.generics.params.clone();
+    let env_lt = get_local_lifetime(Either::Left(&st), &mut st_generic_params);
+    let st_ident = st.ident;
+    let st_generics = &st.generics;
+
+    Ok(quote! {
+        impl <#st_generic_params> ::ez_jni::ToException<#env_lt> for #st_ident #st_generics {
+            fn to_exception(&self, env: &mut ::jni::JNIEnv<#env_lt>) -> ::jni::objects::JThrowable<#env_lt> {
+                let __message = ::std::string::ToString::to_string(self);
+                #exception
+            }
+        }
+    })
+}
+
+pub fn to_exception_enum(mut enm: ItemEnum) -> syn::Result<TokenStream> {
+    let mut errors = Vec::new();
+
+    if enm.variants.is_empty() {
+        errors.push(syn::Error::new(Span::call_site(), "Enum must have at least 1 variant"));
+    }
+
+    // The base class is not needed to create the Exception, but the attribute is still allowed
+    let _ = take_class_attribute(&mut enm.attrs)
+        .map_err(|err| errors.push(err));
+
+    let arms = enm.variants.iter_mut()
+        .map(|variant| {
+            let class = take_class_attribute_required(&mut variant.attrs, variant.ident.span())?;
+            let cause = take_cause_attribute(&mut variant.fields)?
+                .map(|(i, field)| (i, field.ident.clone()));
+
+            // Only bind the cause field (if any) to a variable
+            let pattern = match (&variant.fields, &cause) {
+                (Fields::Unit, _) => quote!(),
+                (Fields::Named(_), Some((_, Some(name)))) => quote!({ #name: __cause, .. }),
+                (Fields::Named(_), _) => quote!({ .. }),
+                (Fields::Unnamed(fields), cause) => {
+                    let bindings = (0..fields.unnamed.len())
+                        .map(|i| match cause {
+                            Some((cause, _)) if *cause == i => quote!(__cause),
+                            _ => quote!(_),
+                        });
+                    quote!(( #(#bindings),* ))
+                }
+            };
+            let exception = exception_from_message(class, cause.map(|_| quote!((*__cause))));
+
+            let ident = &variant.ident;
+            Ok(quote_spanned! {variant.span()=>
+                Self::#ident #pattern => #exception,
+            })
+        })
+        .filter_map(|res: syn::Result<_>| res.map_err(|err| errors.push(err)).ok())
+        .collect::<Box<[_]>>();
+
+    merge_errors(errors)?;
+
+    let mut enm_generic_params = enm.generics.params.clone();
+    let env_lt = get_local_lifetime(Either::Right(&enm), &mut enm_generic_params);
+    let enm_ident = enm.ident;
+    let enm_generics = &enm.generics;
+    Ok(quote! {
+        impl <#enm_generic_params> ::ez_jni::ToException<#env_lt> for #enm_ident #enm_generics {
+            fn to_exception(&self, env: &mut ::jni::JNIEnv<#env_lt>) -> ::jni::objects::JThrowable<#env_lt> {
+                let __message = ::std::string::ToString::to_string(self);
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+/// Finds the field of a **struct** or **enum variant** that has the `cause` attribute, and removes the attribute.
+/// 
+/// The attribute indicates that the field will be converted to the Exception's *cause* (with `ToException`).
+/// 
+/// Returns the index of the field and the field itself, or [`None`] if no field has the attribute.
+fn take_cause_attribute(fields: &mut Fields) -> syn::Result<Option<(usize, &Field)>> {
+    let mut cause = None;
+
+    for (i, field) in fields.iter_mut().enumerate() {
+        let index = field.attrs.iter()
+            .position(|attr| attr.path()
+                .get_ident()
+                .is_some_and(|ident| ident == "cause")
+            );
+
+        if let Some(index) = index {
+            let attr = field.attrs.remove(index);
+            if !matches!(attr.meta, syn::Meta::Path(_)) {
+                return Err(syn::Error::new(attr.meta.span(), "\"cause\" attribute does not take a value"))
+            }
+            if cause.is_some() {
+                return Err(syn::Error::new(attr.span(), "Only 1 field can have the \"cause\" attribute"))
+            }
+            cause = Some(i);
+        }
+    }
+
+    Ok(cause.map(|i| (i, fields.iter().nth(i).unwrap())))
+}
+
+/// Builds an expression that creates a *Java Exception* of **class**,
+/// passing the variable `__message` (a [`String`]) to the Class' *constructor*.
+/// 
+/// If **cause** is [`Some`], it is the expression used to read the *cause* field,
+/// which is converted with `ToException` and also passed to the constructor.
+/// The converted cause can be **null** (e.g. for an `Option` field that is `None`), which Java accepts as a cause.
+fn exception_from_message(class: ClassPath, cause: Option<TokenStream>) -> TokenStream {
+    let span = class.span();
+    let mut parameters = Punctuated::new();
+    parameters.push(Parameter::new(
+        Type::Single(InnerType::Object(syn::parse_quote_spanned!(span=> java.lang.String))),
+        quote!(__message)
+    ));
+    if let Some(cause) = cause {
+        parameters.push(Parameter::new(
+            Type::Single(InnerType::Object(syn::parse_quote_spanned!(span=> java.lang.Throwable))),
+            quote_spanned! {span=> {
+                use ::std::borrow::BorrowMut as _;
+                ::ez_jni::ToException::to_exception(&(#cause), env.borrow_mut())
+            } }
+        ));
+    }
+
+    let ctor = jni_call_constructor(ConstructorCall {
+        class,
+        parameters,
+        err_type: None,
+    });
+
+    quote! { ::jni::objects::JThrowable::from(#ctor) }
+}
@@ -12,8 +12,9 @@ use syn::{parse::{Parse, ParseStream, Parser}, punctuated::Punctuated, AngleBrac
 use itertools::Itertools as _;
 use std::{cell::RefCell, collections::{HashMap, HashSet}};
 use crate::{
-    types::{ClassPath, SigType, SpecialCaseConversion, InnerType},
-    utils::{merge_errors, take_class_attribute, take_class_attribute_required, Spanned}
+    call::{jni_call, jni_call_constructor, gen_arg_vars_defs, ConstructorCall, MethodCall, ObjectMethod, Parameter, Return},
+    types::{ClassPath, SigType, SpecialCaseConversion, InnerType, Type},
+    utils::{first_char_uppercase, merge_errors, take_class_attribute, take_class_attribute_required, Spanned}
 };
 
 /// Properties parsed from the value of an [`Attribute`][syn::Attribute].
@@ -376,3 +377,144 @@ fn construct_variants<'a>(variants: impl Iterator<Item = &'a mut Variant> + 'a)
             })
         })
 }
+
+/// Checks whether a **struct** or **enum variant** has the `constructor` attribute, and removes it from the list of attributes.
+/// 
+/// The attribute indicates that the Java Object is constructed by passing all fields to the Class' *constructor*.
+/// See [`object_from_fields()`].
+fn take_constructor_attribute(attributes: &mut Vec<syn::Attribute>) -> syn::Result<bool> {
+    let index = attributes.iter()
+        .position(|attr| attr.path()
+            .get_ident()
+            .is_some_and(|ident| ident == "constructor")
+        );
+
+    match index {
+        Some(index) => {
+            let attr = attributes.remove(index);
+            match &attr.meta {
+                syn::Meta::Path(_) => Ok(true),
+                _ => Err(syn::Error::new(attr.meta.span(), "\"constructor\" attribute does not take a value"))
+            }
+        },
+        None => Ok(false)
+    }
+}
+
+/// Builds a [`Parameter`] that passes the value of a *struct field* to a JNI call.
+/// 
+/// **accessor** is the expression that reads the field's value (e.g. `self.field`).
+/// Primitives and `String`s are converted by the JNI call itself,
+/// and any other type is converted with `ToObject`.
+fn field_parameter(field: &Field, ty: &InnerType, accessor: &TokenStream) -> Parameter {
+    let value = match ty {
+        InnerType::RustPrimitive { .. } | InnerType::JavaPrimitive { .. } => accessor.clone(),
+        InnerType::Object(class) if class.to_jni_class_path() == "java/lang/String" => accessor.clone(),
+        InnerType::Object(_) | InnerType::Array(_) => quote_spanned! {field.span()=> {
+            use ::std::borrow::BorrowMut as _;
+            ::ez_jni::ToObject::to_object(&(#accessor), env.borrow_mut())
+        } }
+    };
+
+    Parameter::new(Type::Single(ty.clone()), value)
+}
+
+/// Builds an expression that creates a *Java Object* of **class** with the data in the **fields** of a *struct or enum variant*.
+/// This is the inverse of [`struct_constructor()`].
+/// 
+/// **accessors** are the expressions used to read the value of each field (e.g. `self.field` or `(*field)`),
+/// and must be in the same order as the **fields**.
+/// 
+/// If **use_ctor** is `true`, the values of all the fields are passed to the Class' *constructor* in the order they were declared.
+/// Otherwise, the Object is created with the Class' *default constructor*,
+/// and then each of the Object's *members* are assigned the values of the fields.
+/// 
+/// See [`FieldAttr`] for syntax.
+fn object_from_fields(class: ClassPath, fields: &Fields, accessors: &[TokenStream], use_ctor: bool) -> syn::Result<TokenStream> {
+    let mut errors = Vec::new();
+
+    let params = fields.iter()
+        .zip(accessors)
+        .map(|(field, accessor)| {
+            let attr = FieldAttr::get_from_attrs(field)?;
+            let ty = get_type_from_field(field, attr.class.clone())?;
+            Ok((attr, field_parameter(field, &ty, accessor), field))
+        })
+        .filter_map(|res: syn::Result<_>| res.map_err(|err| errors.push(err)).ok())
+        .collect::<Vec<_>>();
+
+    merge_errors(errors)?;
+
+    if use_ctor {
+        return Ok(jni_call_constructor(ConstructorCall {
+            class,
+            parameters: params.into_iter()
+                .map(|(_, param, _)| param)
+                .collect(),
+            err_type: None,
+        }))
+    }
+
+    let class_name = class.to_string();
+    let ctor = jni_call_constructor(ConstructorCall {
+        class,
+        parameters: Punctuated::new(),
+        err_type: None,
+    });
+    errors = Vec::new();
+
+    // Assign the value of each field to a member of the Object
+    let assignments = params.into_iter()
+        .map(|(attr, param, field)| {
+            // Assign the value to the Object's field (a.k.a. member)
+            let set_field = |name: String, setter_fallback: bool| {
+                let var = gen_arg_vars_defs(Some(&param).into_iter());
+                let variant = param.jni_variant(Ident::new("__param_0", field.span()));
+                let sig_ty = param.sig_type();
+                let error_msg = format!("Failed to set field {name} of {class_name}: {{err}}");
+
+                quote_spanned! {field.span()=> {
+                    #var
+                    ::ez_jni::utils::set_field(&object, #name, #sig_ty, #variant, #setter_fallback, env)
+                        .unwrap_or_else(|err| panic!(#error_msg));
+                } }
+            };
+
+            Ok(if let Some(name) = attr.name {
+                // Use the "name" of the field attribute
+                set_field(name.to_string(), false)
+            } else if let Some(call) = attr.call {
+                // The *getter method* in "call" must have a *setter* counterpart that takes the value
+                let call_str = call.to_string();
+                let property = ["get", "is"].into_iter()
+                    .find_map(|prefix| call_str.strip_prefix(prefix))
+                    .filter(|property| !property.is_empty())
+                    .ok_or_else(|| syn::Error::new(call.span(), format!("Can't infer the setter method for \"{call_str}\" because it does not start with \"get\" or \"is\". Use the \"constructor\" attribute instead.")))?;
+                let setter = Ident::new(&format!("set{}", first_char_uppercase(property.to_string())), call.span());
+
+                let call = jni_call(MethodCall {
+                    call_type: Either::Right(ObjectMethod(quote!(object), None)),
+                    super_class: None,
+                    method_name: setter,
+                    parameters: Some(param).into_iter().collect(),
+                    return_type: Return::new_void(call.span()),
+                });
+                quote_spanned! {field.span()=> #call; }
+            } else if let Some(name) = &field.ident {
+                // Use the name of the field, and also call "set{Name}" if field not found
+                set_field(name.to_string().to_case(Case::Camel), true)
+            } else {
+                return Err(syn::Error::new(field.span(), "Field must have \"name\" or \"call\" properties if it is unnamed. See the 'field' attribute. Alternatively, use the \"constructor\" attribute."))
+            })
+        })
+        .filter_map(|res: syn::Result<_>| res.map_err(|err| errors.push(err)).ok())
+        .collect::<Box<[_]>>();
+
+    merge_errors(errors)?;
+
+    Ok(quote! { {
+        let object = #ctor;
+        #(#assignments)*
+        object
+    } })
+}
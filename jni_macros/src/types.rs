@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote_spanned;
-use syn::{bracketed, parse::{discouraged::Speculative as _, Parse}, punctuated::Punctuated, Ident, LitStr, Token};
+use quote::{quote_spanned, ToTokens, TokenStreamExt as _};
+use syn::{bracketed, ext::IdentExt as _, parse::{discouraged::Speculative as _, Parse, ParseStream}, punctuated::Punctuated, Ident, LitStr, Token};
 use itertools::Itertools as _;
 use std::{fmt::{Display, Debug}, str::FromStr};
 use crate::utils::{join_spans, Spanned};
@@ -16,7 +16,65 @@ pub trait SigType {
     fn sig_char(&self) -> Ident;
     /// The Type that is used in the signature of the method call. e.g. `"V"` or `"Ljava/lang/String;"`.
     /// It is the *uppercase [`SigType::sig_char`]*, and if it is `L` it is followed by the ClassPath and a `;`.
-    fn sig_type(&self) -> LitStr;
+    /// 
+    /// This is only known at runtime if the type is (or contains) a [`RustType`].
+    fn sig_type(&self) -> Signature;
+}
+
+/// The *signature* of a [`SigType`], which is either a string literal,
+/// or an expression that evaluates to the signature (of type `Cow<str>`) at runtime.
+/// 
+/// Converting this to tokens results in an expression of type `&str`.
+#[derive(Clone)]
+pub enum Signature {
+    Static(LitStr),
+    /// The signature of a [`RustType`], obtained from its `JavaClass` implementation.
+    /// Evaluating it will `panic!` if the signature is not of an Object or Array.
+    Runtime(TokenStream),
+}
+impl Signature {
+    /// Get the value of a [`Static`][Signature::Static] signature.
+    /// 
+    /// Only call this on signatures of types that can't contain a [`RustType`] (e.g. [`InnerType`]).
+    /// 
+    /// # Panics
+    /// If this is a [`Runtime`][Signature::Runtime] signature.
+    pub fn value(&self) -> String {
+        match self {
+            Self::Static(lit) => lit.value(),
+            Self::Runtime(_) => panic!("Unreachable code; The signature of a Rust type is only known at runtime"),
+        }
+    }
+    pub fn set_span(&mut self, span: Span) {
+        if let Self::Static(lit) = self {
+            lit.set_span(span)
+        }
+    }
+    /// Concatenate the signatures of the **params** and **return type** to create the signature of a *method*,
+    /// e.g. `(Ljava/lang/String;)V`.
+    /// 
+    /// The result is [`Static`][Signature::Static] if all components are static.
+    pub fn method(params: impl IntoIterator<Item = Self>, return_sig: Self) -> Self {
+        let params = params.into_iter().collect::<Vec<_>>();
+
+        if params.iter().chain(Some(&return_sig)).all(|sig| matches!(sig, Self::Static(_))) {
+            let params_sig = params.iter()
+                .map(Self::value)
+                .collect::<String>();
+            Self::Static(LitStr::new(&format!("({params_sig}){}", return_sig.value()), Span::call_site()))
+        } else {
+            let format = format!("({}){{}}", "{}".repeat(params.len()));
+            Self::Runtime(quote::quote! { ::std::format!(#format, #(#params,)* #return_sig) })
+        }
+    }
+}
+impl ToTokens for Signature {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Static(lit) => lit.to_tokens(tokens),
+            Self::Runtime(expr) => tokens.append_all(quote_spanned!(expr.span()=> &*(#expr))),
+        }
+    }
 }
 
 /// Perform some kind of necessary conversion between *Rust* and *Java* values.
@@ -45,14 +103,17 @@ pub trait SpecialCaseConversion {
 pub enum Type {
     Single(InnerType),
     Array(ArrayType),
-    // Might also add generics
+    /// A Rust type that is converted to a Java Object with `ToObject`.
+    Rust(RustType),
 }
 impl Type {
-    /// Returns whether the [`InnerType`] of the type is a *primitive*.
+    /// Returns whether the [`InnerType`] of the type is a *primitive*,
+    /// or whether the [`RustType`] is converted to and from a *primitive*.
     /// Returns `false` if this is an [`Array`][Type::Array].
     pub fn is_primitive(&self) -> bool {
         match self {
             Self::Single(InnerType::RustPrimitive { .. } | InnerType::JavaPrimitive { .. }) => true,
+            Self::Rust(ty) => ty.primitive().is_some(),
             Self::Single(_) | Self::Array(_) => false,
         }
     }
@@ -62,12 +123,14 @@ impl SigType for Type {
         match self {
             Self::Single(ty) => ty.sig_char(),
             Self::Array(array) => array.sig_char(),
+            Self::Rust(ty) => ty.sig_char(),
         }
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         match self {
             Self::Single(ty) => ty.sig_type(),
             Self::Array(array) => array.sig_type(),
+            Self::Rust(ty) => ty.sig_type(),
         }
     }
 }
@@ -75,13 +138,15 @@ impl SpecialCaseConversion for Type {
     fn convert_java_to_rust(&self, value: &TokenStream) -> Option<TokenStream> {
         match self {
             Self::Single(ty) => ty.convert_java_to_rust(value),
-            Self::Array(array) => array.convert_java_to_rust(value)
+            Self::Array(array) => array.convert_java_to_rust(value),
+            Self::Rust(ty) => ty.convert_java_to_rust(value),
         }
     }
     fn convert_rust_to_java(&self, value: &TokenStream) -> Option<TokenStream> {
         match self {
             Self::Single(ty) => ty.convert_rust_to_java(value),
-            Self::Array(array) => array.convert_rust_to_java(value)
+            Self::Array(array) => array.convert_rust_to_java(value),
+            Self::Rust(ty) => ty.convert_rust_to_java(value),
         }
     }
 }
@@ -89,7 +154,8 @@ impl Spanned for Type {
     fn span(&self) -> Span {
         match self {
             Self::Single(ty) => ty.span(),
-            Self::Array(array) => array.span()
+            Self::Array(array) => array.span(),
+            Self::Rust(ty) => ty.span(),
         }
     }
 }
@@ -98,6 +164,8 @@ impl Parse for Type {
         // Check if the Type is wrapped in Brackets (Array)
         Ok(if input.lookahead1().peek(syn::token::Bracket) {
             Self::Array(input.parse()?)
+        } else if RustType::peek(input) {
+            Self::Rust(input.parse()?)
         } else {
             Self::Single(input.parse()?)
         })
@@ -107,11 +175,166 @@ impl Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Single(ty) => Display::fmt(ty, f),
-            Self::Array(array) => Display::fmt(array, f)
+            Self::Array(array) => Display::fmt(array, f),
+            Self::Rust(ty) => Display::fmt(ty, f),
         }
     }
 }
 
+/// A **Rust** type that is used in place of a *Java* type, such as `MyPoint`, `crate::MyPoint`, or `Vec<MyPoint>`.
+/// 
+/// The type must implement `JavaClass` (which provides its *signature*),
+/// and `FromObject` or `ToObject` to be converted from or to a Java Object.
+/// 
+/// Any path that is not a *primitive*, `String`, or a [`ClassPath`] is parsed as a [`RustType`].
+/// [`ClassPath`]s are separated by *dots* and have at least 2 components, so they are never confused with Rust paths.
+#[derive(Clone)]
+pub struct RustType(pub syn::TypePath);
+impl RustType {
+    /// Returns whether the next tokens in **input** are a [`RustType`] and not a *Java* type.
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        if fork.peek(Token![::]) {
+            return true
+        }
+        let ident = match fork.call(Ident::parse_any) {
+            Ok(ident) => ident.to_string(),
+            Err(_) => return false,
+        };
+        // These have special meaning in the macros
+        if matches!(ident.as_str(), "void" | "Option" | "Result") {
+            return false
+        }
+        // A path with more components or with generics
+        if fork.peek(Token![::]) || fork.peek(Token![<]) {
+            return true
+        }
+        // A ClassPath or varargs
+        if fork.peek(Token![.]) || fork.peek(Token![$]) {
+            return false
+        }
+
+        !(ident == "String"
+            || RustPrimitive::from_str(&ident).is_ok()
+            || JavaPrimitive::from_str(&ident).is_ok())
+    }
+    /// The *Java primitive* that the type is converted to and from,
+    /// if it is a wrapper of an *unsigned integer* (e.g. `Checked<u32>` is converted to and from `int`).
+    /// 
+    /// Returns [`None`] for other Rust types, which are converted to and from Objects.
+    pub fn primitive(&self) -> Option<JavaPrimitive> {
+        let segment = self.0.path.segments.last()?;
+        let arg = match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+                syn::GenericArgument::Type(syn::Type::Path(arg)) => arg.path.get_ident()?.to_string(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        match (segment.ident.to_string().as_str(), arg.as_str()) {
+            ("Checked", "u8") => Some(JavaPrimitive::Byte),
+            ("Checked", "u16") | ("Widened", "u8") => Some(JavaPrimitive::Short),
+            ("Checked", "u32") | ("Widened", "u16") => Some(JavaPrimitive::Int),
+            ("Checked", "u64") | ("Widened", "u32") => Some(JavaPrimitive::Long),
+            _ => None,
+        }
+    }
+}
+impl SigType for RustType {
+    fn sig_char(&self) -> Ident {
+        match self.primitive() {
+            Some(primitive) => Ident::new(&primitive.sig_char().to_string(), self.span()),
+            None => Ident::new("l", self.span()),
+        }
+    }
+    fn sig_type(&self) -> Signature {
+        if let Some(primitive) = self.primitive() {
+            let mut sig_type = primitive.sig_type();
+            sig_type.set_span(self.span());
+            return sig_type;
+        }
+        let ty = &self.0;
+        Signature::Runtime(quote_spanned!(self.span()=> ::ez_jni::utils::__object_signature::<#ty>()))
+    }
+}
+impl SpecialCaseConversion for RustType {
+    /// Returns code that converts the Object with the type's `FromObject` implementation.
+    /// 
+    /// Always returns [`Some`].
+    fn convert_java_to_rust(&self, value: &TokenStream) -> Option<TokenStream> {
+        let ty = &self.0;
+        if self.primitive().is_some() {
+            let error = format!("Failed to convert value to {self}: {{err}}");
+            return Some(quote_spanned! {value.span()=>
+                <#ty as ::ez_jni::utils::__Primitive>::__from_primitive(#value)
+                    .unwrap_or_else(|err| panic!(#error))
+            });
+        }
+        let error = format!("Failed to convert Object to {self}: {{err}}");
+        Some(quote_spanned! {value.span()=> {
+            use ::std::borrow::BorrowMut as _;
+            <#ty as ::ez_jni::FromObject>::from_object(&#value, env.borrow_mut())
+                .unwrap_or_else(|err| panic!(#error))
+        } })
+    }
+    /// Returns code that converts the value to an Object with its `ToObject` implementation,
+    /// or to a primitive if the type has a [`primitive()`][RustType::primitive()].
+    /// 
+    /// Always returns [`Some`].
+    fn convert_rust_to_java(&self, value: &TokenStream) -> Option<TokenStream> {
+        if self.primitive().is_some() {
+            let ty = &self.0;
+            return Some(quote_spanned! {value.span()=>
+                <#ty as ::ez_jni::utils::__Primitive>::__to_primitive(&(#value))
+            });
+        }
+        Some(quote_spanned! {value.span()=> {
+            use ::std::borrow::BorrowMut as _;
+            use ::ez_jni::ToObject as _;
+            (#value).to_object(env.borrow_mut())
+        } })
+    }
+}
+impl Spanned for RustType {
+    fn span(&self) -> Span {
+        self.0.to_token_stream().span()
+    }
+}
+impl Parse for RustType {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Parse the path manually because syn would parse the parameter value (in parenthesis) as part of the type.
+        let leading_colon = input.parse::<Option<Token![::]>>()?;
+        let mut segments = Punctuated::new();
+        loop {
+            let ident = input.call(Ident::parse_any)?;
+            let arguments = if input.peek(Token![<]) {
+                syn::PathArguments::AngleBracketed(input.parse()?)
+            } else {
+                syn::PathArguments::None
+            };
+            segments.push_value(syn::PathSegment { ident, arguments });
+
+            match input.parse::<Option<Token![::]>>()? {
+                Some(colons) => segments.push_punct(colons),
+                None => break,
+            }
+        }
+
+        Ok(Self(syn::TypePath { qself: None, path: syn::Path { leading_colon, segments } }))
+    }
+}
+impl Display for RustType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_token_stream().to_string().replace(' ', ""))
+    }
+}
+impl Debug for RustType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
 #[derive(Clone)]
 pub struct ArrayType {
     pub ty: InnerType
@@ -128,9 +351,9 @@ impl SigType for ArrayType {
     fn sig_char(&self) -> Ident {
         Ident::new("l", self.ty.span())
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         let sig_type = self.ty.sig_type();
-        LitStr::new(&format!("[{}", sig_type.value()), sig_type.span())
+        Signature::Static(LitStr::new(&format!("[{}", sig_type.value()), sig_type.span()))
     }
 }
 impl SpecialCaseConversion for ArrayType {
@@ -150,7 +373,7 @@ impl SpecialCaseConversion for ArrayType {
                 let (j_prim, r_prim) = match &self.ty {
                     InnerType::JavaPrimitive { ty, .. } => (*ty, RustPrimitive::from(*ty)),
                     InnerType::RustPrimitive { ty, .. } => (JavaPrimitive::from(*ty), *ty),
-                    InnerType::Object(_) => panic!("Passed Object to function that clearly wants a primitive")
+                    InnerType::Object(_) | InnerType::Array(_) => panic!("Passed Object to function that clearly wants a primitive")
                 };
 
                 let inner_ty = j_prim.sig_type();
@@ -182,18 +405,21 @@ impl SpecialCaseConversion for ArrayType {
                         .collect::<::std::boxed::Box<[_]>>()
                 } }
             },
-            // Build a Rust boxed slice from a Java Array in which the inner type is an Object.
-            InnerType::Object(class) => {
-                // For some reason, class.getName() returns a ClassPath with .dots. instead of /slashes/, so can't use sig_type().
+            // Build a Rust boxed slice from a Java Array in which the inner type is an Object (or another Array).
+            InnerType::Object(_)
+            | InnerType::Array(_) => {
+                // For some reason, class.getName() returns a ClassPath with .dots. instead of /slashes/, so can't use sig_type() directly.
                 // This is the only place where this happens. why??
-                let inner_ty = LitStr::new(&format!("L{};", class.to_string()), class.span());
-                let null_err = format!("Array of {class} contains null elements (at {{i}}). If this is intended, wrap the Class with 'Option' (e.g. Option<{class}>)");
+                let inner_ty = self.ty.sig_type();
+                let inner_ty = LitStr::new(&inner_ty.value().replace('/', "."), inner_ty.span());
+                let elem_name = self.ty.element_name();
+                let null_err = format!("Array of {elem_name} contains null elements (at {{i}}). If this is intended, wrap the Class with 'Option' (e.g. Option<{elem_name}>)");
 
-                // The inner Class of the array might require some conversion
+                // The inner Class (or Array) of the array might require some conversion
                 let conversion = {
                     let element_tokens = quote_spanned!(value.span()=> _element); 
                     // Convert using the variable
-                    class.convert_java_to_rust(&element_tokens)
+                    self.ty.convert_java_to_rust(&element_tokens)
                         .unwrap_or(element_tokens)
                 };
 
@@ -230,7 +456,7 @@ impl SpecialCaseConversion for ArrayType {
                 let j_prim = match &self.ty {
                     InnerType::JavaPrimitive { ty, .. } => *ty,
                     InnerType::RustPrimitive { ty, .. } => JavaPrimitive::from(*ty),
-                    InnerType::Object(_) => panic!("Passed Object to function that clearly wants a primitive")
+                    InnerType::Object(_) | InnerType::Array(_) => panic!("Passed Object to function that clearly wants a primitive")
                 };
                 
                 let new_array_fn = Ident::new(&format!("new_{j_prim}_array"), value.span());
@@ -266,32 +492,34 @@ impl SpecialCaseConversion for ArrayType {
                     ::jni::objects::JObject::from(_jarray)
                 } }
             },
-            // Build a Java Array in which the inner type is an Object.
-            InnerType::Object(class) => {
+            // Build a Java Array in which the inner type is an Object (or another Array).
+            InnerType::Object(_)
+            | InnerType::Array(_) => {
                 let new_array_err = LitStr::new(
-                    &format!("Failed to create Java Object \"{}\" array: {{err}}", class.to_string()),
+                    &format!("Failed to create Java Object \"{}\" array: {{err}}", self.ty.element_name()),
                     value.span(),
                 );
                 let set_val_err = LitStr::new(
                     &format!("Failed to set the value of Object array at index {{_i}}: {{err}}"),
                     value.span(),
                 );
-                let class_path = LitStr::new(&class.to_jni_class_path(), value.span());
+                let class_path = LitStr::new(&self.ty.element_class_path(), value.span());
 
                 // Build the array from Rust Strings (Option allowed).
-                if class.to_jni_class_path() == "java/lang/String" {
+                if class_path.value() == "java/lang/String" {
                     quote_spanned! {value.span()=> {
                         use ::std::borrow::BorrowMut as _;
                         use ::ez_jni::ToObject as _;
 
-                        let _slice = ::std::convert::AsRef::<[_]>::as_ref(&(#value));
+                        let _slice = &(#value);
+                        let _slice = ::std::convert::AsRef::<[_]>::as_ref(_slice);
                         let _jarray = env.new_object_array(
                             _slice.len() as ::jni::sys::jsize,
                             #class_path,
                             unsafe { ::jni::objects::JObject::from_raw(::std::ptr::null_mut()) }
                         )
                             .unwrap_or_else(|err| panic!(#new_array_err));
-                        for (_i, _element) in _slice.into_iter().enumerate() {
+                        for (_i, _element) in _slice.iter().enumerate() {
                             // str, String, Option<T> implement ToObject, so use that to accept any kind of string
                             let _element = _element.to_object(env.borrow_mut());
                             env.set_object_array_element(&_jarray, _i as ::jni::sys::jsize, _element)
@@ -300,11 +528,11 @@ impl SpecialCaseConversion for ArrayType {
                         ::jni::objects::JObject::from(_jarray)
                     } }
                 } else {
-                    // The inner Class of the array might require some conversion
+                    // The inner Class (or Array) of the array might require some conversion
                     let conversion = {
                         let element_tokens = quote_spanned!(value.span()=> _element); 
                         // Convert using the variable
-                        class.convert_rust_to_java(&element_tokens)
+                        self.ty.convert_rust_to_java(&element_tokens)
                             .unwrap_or(element_tokens)
                     };
     
@@ -317,8 +545,10 @@ impl SpecialCaseConversion for ArrayType {
                             unsafe { ::jni::objects::JObject::from_raw(::std::ptr::null_mut()) }
                         )
                             .unwrap_or_else(|err| panic!(#new_array_err));
-                        for (_i, _element) in _slice.into_iter().enumerate() {
-                            env.set_object_array_element(&_jarray, _i as ::jni::sys::jsize, #conversion)
+                        for (_i, _element) in _slice.iter().enumerate() {
+                            // The conversion could borrow env, so it can't be an argument of set_object_array_element
+                            let _element = #conversion;
+                            env.set_object_array_element(&_jarray, _i as ::jni::sys::jsize, _element)
                                 .unwrap_or_else(|err| panic!(#set_val_err));
                         }
                         ::jni::objects::JObject::from(_jarray)
@@ -361,6 +591,27 @@ pub enum InnerType {
     JavaPrimitive { ident: Ident, ty: JavaPrimitive },
     RustPrimitive { ident: Ident, ty: RustPrimitive },
     Object(ClassPath),
+    /// The element of a *multi-dimensional* Array, e.g. the `[int]` in `[[int]]`.
+    Array(Box<ArrayType>),
+}
+impl InnerType {
+    /// The name of the type as it appears in error messages of the generated code,
+    /// e.g. `java.lang.String` or `[int]`.
+    pub fn element_name(&self) -> String {
+        match self {
+            Self::Object(class) => class.to_string(),
+            ty => ty.to_string(),
+        }
+    }
+    /// The Class used to create a *Java Array* with elements of this type (for Objects and Arrays).
+    /// This is the *class path* for Objects (e.g. `java/lang/String`),
+    /// and the *signature* for Arrays (e.g. `[I`).
+    pub fn element_class_path(&self) -> String {
+        match self {
+            Self::Object(class) => class.to_jni_class_path(),
+            ty => ty.sig_type().value(),
+        }
+    }
 }
 impl Spanned for InnerType {
     fn span(&self) -> Span {
@@ -368,6 +619,7 @@ impl Spanned for InnerType {
             Self::JavaPrimitive { ident, .. }
             | Self::RustPrimitive { ident, .. } => ident.span(),
             Self::Object(class) => class.span(),
+            Self::Array(array) => array.span(),
         }
     }
 }
@@ -385,9 +637,10 @@ impl SigType for InnerType {
                 sig_char
             },
             Self::Object(class) => class.sig_char(),
+            Self::Array(array) => array.sig_char(),
         }
     }
-    fn sig_type(&self) -> LitStr {
+    fn sig_type(&self) -> Signature {
         match self {
             Self::JavaPrimitive { ident, ty } => {
                 let mut sig_type = ty.sig_type();
@@ -400,6 +653,7 @@ impl SigType for InnerType {
                 sig_type
             },
             Self::Object(class) => class.sig_type(),
+            Self::Array(array) => array.sig_type(),
         }
     }
 }
@@ -409,6 +663,7 @@ impl SpecialCaseConversion for InnerType {
             Self::RustPrimitive { ty, .. } => ty.convert_java_to_rust(value),
             Self::JavaPrimitive { ty, .. } => RustPrimitive::from(*ty).convert_java_to_rust(value),
             Self::Object(class) => class.convert_java_to_rust(value),
+            Self::Array(array) => array.convert_java_to_rust(value),
         }
     }
     fn convert_rust_to_java(&self, value: &TokenStream) -> Option<TokenStream> {
@@ -416,11 +671,17 @@ impl SpecialCaseConversion for InnerType {
             Self::RustPrimitive { ty, .. } => ty.convert_rust_to_java(value),
             Self::JavaPrimitive { ty, .. } => RustPrimitive::from(*ty).convert_rust_to_java(value),
             Self::Object(class) => class.convert_rust_to_java(value),
+            Self::Array(array) => array.convert_rust_to_java(value),
         }
     }
 }
 impl Parse for InnerType {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // The element of a multi-dimensional Array
+        if input.lookahead1().peek(syn::token::Bracket) {
+            return Ok(Self::Array(Box::new(input.parse()?)))
+        }
+
         let fork = input.fork();
         let ident = fork.parse::<Ident>()?;
         let ident_str = ident.to_string();
@@ -447,6 +708,8 @@ impl Parse for InnerType {
                     input.advance_to(&fork);
                     Ok(Self::JavaPrimitive { ident, ty })
                 }
+                // Rust types are only allowed as the outermost type (e.g. not as the element of an Array)
+                None if RustType::peek(input) => Err(syn::Error::new(ident.span(), "A Rust type can't be used here; use a Rust type that contains it instead (e.g. 'Vec<T>' instead of '[T]').")),
                 None => return Ok(Self::Object(input.parse()?)),
             },
         }
@@ -459,6 +722,7 @@ impl Display for InnerType {
             // Convert form Rust to Java
             Self::RustPrimitive { ty, .. } => JavaPrimitive::from(*ty).to_string(),
             Self::Object(class) => format!("Object({})", class.to_jni_class_path()),
+            Self::Array(array) => array.to_string(),
         };
         f.write_str(&s)
     }
@@ -551,8 +815,8 @@ impl SigType for ClassPath {
     fn sig_char(&self) -> Ident {
         Ident::new("l", self.span())
     }
-    fn sig_type(&self) -> LitStr {
-        LitStr::new(&format!("L{};", self.to_jni_class_path()), self.span())
+    fn sig_type(&self) -> Signature {
+        Signature::Static(LitStr::new(&format!("L{};", self.to_jni_class_path()), self.span()))
     }
 }
 impl SpecialCaseConversion for ClassPath {
@@ -612,7 +876,15 @@ impl Parse for ClassPath {
         }
 
         // Parse the dot-separated section `me.author.Class`
-        let mut path = Punctuated::<Ident, Token![.]>::parse_separated_nonempty(input)?;
+        let mut path = Punctuated::<Ident, Token![.]>::new();
+        loop {
+            path.push_value(input.parse()?);
+            // Stop before the `...` of a varargs parameter, which is not part of the path
+            if !input.peek(Token![.]) || input.peek(Token![..]) {
+                break;
+            }
+            path.push_punct(input.parse()?);
+        }
         // The class is the final component of the path
         let class = match path.pop() {
             Some(class) => class.into_value(),
@@ -791,8 +1063,8 @@ impl SigType for JavaPrimitive {
         };
         Ident::new(&c.to_string(), Span::call_site())
     }
-    fn sig_type(&self) -> LitStr {
-        LitStr::new(
+    fn sig_type(&self) -> Signature {
+        Signature::Static(LitStr::new(
             self.sig_char()
                 .to_string()
                 .chars()
@@ -802,7 +1074,7 @@ impl SigType for JavaPrimitive {
                 .collect::<String>()
                 .as_str(),
             Span::call_site()
-        )
+        ))
     }
 }
 impl FromStr for JavaPrimitive {
@@ -4,7 +4,7 @@ use either::Either;
 use proc_macro2::{TokenStream, Span};
 use quote::{ToTokens, TokenStreamExt as _};
 use syn::{ItemEnum, ItemStruct, LitStr};
-use crate::types::{ClassPath, SigType};
+use crate::types::{ClassPath, SigType, Signature};
 
 /// The same as [`syn::spanned::Spanned`].
 /// 
@@ -93,15 +93,12 @@ pub fn first_char_uppercase(s: String) -> String {
 /// as each type has its own signature component that it adds to the whole signature.
 /// 
 /// The returned string will look something like this: `(Ljava.lang.String;)V`.
-pub fn gen_signature<'a, P, R>(params: impl Iterator<Item = &'a P>, return_type: &R) -> LitStr
+/// It is only known at runtime if any of the types is a [`RustType`][crate::types::RustType].
+pub fn gen_signature<'a, P, R>(params: impl Iterator<Item = &'a P>, return_type: &R) -> Signature
 where P: SigType + 'a,
       R: SigType
 {
-    let params_sig = params
-        .map(|p| p.sig_type().value())
-        .collect::<String>();
-    let return_sig = return_type.sig_type().value();
-    LitStr::new(&format!("({params_sig}){return_sig}"), Span::call_site())
+    Signature::method(params.map(SigType::sig_type), return_type.sig_type())
 }
 
 /// Find and parse the `class` attribute of a **function**, **struct** or **enum variant** and return the Path to the Java Class.
@@ -0,0 +1,140 @@
+//! Caches for the *Class* and *Method IDs* looked up by the code generated by [`call!`][crate::call!] and [`new!`][crate::new!].
+//!
+//! Each call site has its own `static` cache, so the IDs are only looked up the first time the call site is reached.
+//! The cached IDs are bound to the [`JavaVM`][jni::JavaVM] they were looked up in, and are looked up again if the call site is reached in another VM.
+//!
+//! A call site does not always have the same *signature*:
+//! the signature of a Rust type is only known at runtime, and a call site in a generic function is shared by all of its instantiations.
+//! So entries are also keyed by the signature, and an ID is never used for a signature other than the one it was looked up with.
+use std::sync::RwLock;
+use jni::{
+    errors::{Error as JNIError, Result as JNIResult},
+    objects::{GlobalRef, JClass, JMethodID, JObject, JStaticMethodID},
+    sys::JavaVM,
+    JNIEnv,
+};
+
+/// An entry in an [`IdCache`].
+struct CacheEntry<Id> {
+    /// The VM that the **class** and **id** belong to.
+    vm: usize,
+    /// The signature that the **id** was looked up with.
+    sig: Box<str>,
+    class: GlobalRef,
+    id: Id,
+}
+
+/// Holds the *Class* and ID of a *Method* (of type `Id`) for a `call!` or `new!` call site.
+///
+/// The cache holds up to `MAX_ENTRIES` entries, one for each signature
+/// (and for each Class, because call sites of *object methods* can be reached with Objects of different Classes).
+pub struct IdCache<Id> {
+    entries: RwLock<Vec<CacheEntry<Id>>>,
+}
+/// The maximum number of entries that an [`IdCache`] holds.
+/// The oldest entry is removed when another entry is added to a full cache.
+const MAX_ENTRIES: usize = 8;
+impl<Id: Copy> IdCache<Id> {
+    pub const fn new() -> Self {
+        Self { entries: RwLock::new(Vec::new()) }
+    }
+
+    /// Gets the cached Class and ID of the first entry with the signature **sig** for which **is_valid** returns `true`,
+    /// or calls **lookup** to get the Class and ID and stores them in the cache.
+    ///
+    /// Returns a new reference to the Class so that it stays valid even if the entry is replaced by another thread.
+    fn get_or_lookup<'local>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        sig: &str,
+        is_valid: impl Fn(&mut JNIEnv<'local>, &GlobalRef) -> bool,
+        lookup: impl FnOnce(&mut JNIEnv<'local>) -> JNIResult<(JClass<'local>, Id)>,
+    ) -> JNIResult<(GlobalRef, Id)> {
+        let vm = current_vm(env)?;
+        if let Some(cached) = self.entries.read().unwrap().iter()
+            .find(|entry| entry.vm == vm && &*entry.sig == sig && is_valid(env, &entry.class))
+            .map(|entry| (entry.class.clone(), entry.id))
+        {
+            return Ok(cached);
+        }
+
+        // The lock is not held during the lookup because finding a Class can run its static initializer,
+        // which could reach this call site again (e.g. through a native method).
+        // If several threads look up the same IDs, the last one to store its entry wins.
+        let (class, id) = lookup(env)?;
+        let global = env.new_global_ref(&class)?;
+        env.delete_local_ref(class)?;
+
+        let mut entries = self.entries.write().unwrap();
+        // The old VM might not exist anymore, so the global references of its entries can't be deleted
+        let (current, old): (Vec<_>, Vec<_>) = std::mem::take(&mut *entries).into_iter()
+            .partition(|entry| entry.vm == vm);
+        for old in old {
+            std::mem::forget(old.class);
+        }
+        *entries = current;
+        if entries.len() >= MAX_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push(CacheEntry { vm, sig: sig.into(), class: global.clone(), id });
+
+        Ok((global, id))
+    }
+}
+impl<Id: Copy> Default for IdCache<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl IdCache<JStaticMethodID> {
+    /// Gets the Class and ID of a *static method* to call with [`JNIEnv::call_static_method_unchecked()`].
+    pub fn static_method(&self, env: &mut JNIEnv, class: &str, name: &str, sig: &str) -> JNIResult<(GlobalRef, JStaticMethodID)> {
+        self.get_or_lookup(env, sig, |_, _| true, |env| {
+            let class = env.find_class(class)?;
+            let id = env.get_static_method_id(&class, name, sig)?;
+            Ok((class, id))
+        })
+    }
+}
+impl IdCache<JMethodID> {
+    /// Gets the Class and ID of a *constructor* to call with [`JNIEnv::new_object_unchecked()`].
+    pub fn constructor(&self, env: &mut JNIEnv, class: &str, sig: &str) -> JNIResult<(GlobalRef, JMethodID)> {
+        self.nonvirtual_method(env, class, "<init>", sig)
+    }
+    /// Gets the Class and ID of a method that is always looked up in **class**,
+    /// to call with [`__call_nonvirtual_method_unchecked()`][crate::utils::__call_nonvirtual_method_unchecked].
+    pub fn nonvirtual_method(&self, env: &mut JNIEnv, class: &str, name: &str, sig: &str) -> JNIResult<(GlobalRef, JMethodID)> {
+        self.get_or_lookup(env, sig, |_, _| true, |env| {
+            let class = env.find_class(class)?;
+            let id = env.get_method_id(&class, name, sig)?;
+            Ok((class, id))
+        })
+    }
+    /// Gets the ID of an *object method* to call with [`JNIEnv::call_method_unchecked()`].
+    ///
+    /// A cached ID is only used if **object** is an instance of the Class that the ID was looked up in.
+    /// Otherwise the ID is looked up in the Class of **object** and cached for that Class.
+    pub fn method<'local, 'other_local>(&self, env: &mut JNIEnv<'local>, object: impl AsRef<JObject<'other_local>>, name: &str, sig: &str) -> JNIResult<JMethodID> {
+        let object = object.as_ref();
+        // IsInstanceOf returns true for null, but calling a method on null would crash the VM
+        if object.is_null() {
+            return Err(JNIError::NullPtr("call_method obj argument"));
+        }
+
+        self.get_or_lookup(
+            env,
+            sig,
+            |env, class| env.is_instance_of(object, class).unwrap_or(false),
+            |env| {
+                let class = env.get_object_class(object)?;
+                let id = env.get_method_id(&class, name, sig)?;
+                Ok((class, id))
+            }
+        ).map(|(_, id)| id)
+    }
+}
+
+/// Gets an identifier of the [`JavaVM`] that **env** belongs to.
+fn current_vm(env: &JNIEnv) -> JNIResult<usize> {
+    Ok(env.get_java_vm()?.get_java_vm_pointer() as *mut JavaVM as usize)
+}
@@ -1,10 +1,10 @@
 use either::Either;
 use jni::{
     JNIEnv,
-    objects::{JObject, JString, JThrowable},
+    objects::{JClass, JObject, JString, JThrowable, JValue},
 };
-use std::{any::Any, sync::RwLock};
-use crate::FromException;
+use std::{any::Any, backtrace::{Backtrace, BacktraceStatus}, borrow::Cow, cell::{Cell, RefCell}, sync::{Once, RwLock}};
+use crate::{FromException, ToException};
 use crate::eprintln;
 
 /// A lot like [std::panic::Location], but uses a [String] instead of `&str`.
@@ -27,7 +27,75 @@ impl std::fmt::Display for PanicLocation {
         write!(f, "{}:{}:{}", self.file, self.line, self.col)
     }
 }
-static PANIC_LOCATION: RwLock<Option<PanicLocation>> = RwLock::new(None);
+/// Data about a `panic!` that is collected by the *panic hook*.
+struct PanicData {
+    location: Option<PanicLocation>,
+    /// Is only captured if `RUST_BACKTRACE` is set.
+    backtrace: Backtrace,
+}
+thread_local! {
+    /// The data of the last `panic!` that occurred in a [`catch_throw()`] in this thread.
+    static PANIC_DATA: RefCell<Option<PanicData>> = const { RefCell::new(None) };
+    /// The number of [`catch_throw()`] calls this thread is currently in (they can be nested if Rust calls Java that calls Rust).
+    /// The *panic hook* only collects [`PanicData`] if this is not `0`.
+    static CATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+static INSTALL_HOOK: Once = Once::new();
+
+/// Installs the *panic hook* that collects [`PanicData`] for [`catch_throw()`].
+/// The hook is only installed once, and calls the *previous hook* (e.g. the default hook, or one set by the user)
+/// so that it still runs when panicking inside a [`jni_fn`][ez_jni_macros::jni_fn].
+/// 
+/// Note that a hook set *after* this is called will replace this one.
+fn install_panic_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            // Only panics that will be caught by catch_throw() need the data
+            if CATCH_DEPTH.try_with(Cell::get).unwrap_or(0) > 0 {
+                let _ = PANIC_DATA.try_with(|panic_data| *panic_data.borrow_mut() = Some(PanicData {
+                    location: info.location().map(PanicLocation::from),
+                    backtrace: Backtrace::capture(),
+                }));
+            }
+            previous(info)
+        }));
+    });
+}
+
+/// The Class of the Exception that is thrown when a [`jni_fn`][ez_jni_macros::jni_fn] panics.
+static PANIC_EXCEPTION_CLASS: RwLock<Cow<'static, str>> = RwLock::new(Cow::Borrowed("java/lang/RuntimeException"));
+
+/// Set the **Class** of the Exception that is thrown when a [`jni_fn`][ez_jni_macros::jni_fn] `panics!`.
+/// By default, this is `java.lang.RuntimeException`.
+/// 
+/// The **class** can be written with *dots* or *slashes* (e.g. `me.author.RustPanic` or `me/author/RustPanic`),
+/// and must have a constructor that takes a `java.lang.String`, which will be a message like `panicked at src/lib.rs:10:5: message`.
+/// 
+/// The Class can also declare any of the following (*non-static*) fields,
+/// which will be set with the data of the `panic!`:
+/// 
+/// ```java
+/// public class RustPanic extends RuntimeException {
+///     public String panicMessage; // The message that was passed to panic!
+///     public String file;
+///     public int line;
+///     public int column;
+///     public String backtrace;    // Only set if RUST_BACKTRACE is set
+///     public RustPanic(String message) { super(message); }
+/// }
+/// ```
+/// 
+/// If the Class does not declare a `backtrace` field, the backtrace will be appended to the Exception's message instead.
+pub fn set_panic_exception_class(class: impl Into<Cow<'static, str>>) {
+    let class = class.into();
+    let class = if class.contains('.') {
+        Cow::Owned(class.replace('.', "/"))
+    } else {
+        class
+    };
+    *PANIC_EXCEPTION_CLASS.write().unwrap() = class;
+}
 
 /// Runs a Rust function and returns its value, catching any `panics!` and throwing them as Java Exceptions.
 ///
@@ -40,15 +108,13 @@ pub fn catch_throw<'local, R>(
     env: &mut JNIEnv<'local>,
     f: impl FnOnce(&mut JNIEnv<'local>) -> R,
 ) -> R {
-    std::panic::set_hook(Box::new(|info| {
-        if let Ok(mut panic_location) = PANIC_LOCATION.write() {
-            if let Some(location) = info.location() {
-                *panic_location = Some(PanicLocation::from(location))
-            }
-        }
-    }));
+    install_panic_hook();
 
-    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(env))) {
+    CATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(env)));
+    CATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+    match result {
         Ok(r) => r,
         Err(payload) => {
             throw_panic(env, payload);
@@ -56,7 +122,38 @@ pub fn catch_throw<'local, R>(
         }
     }
 }
+/// The same as [`catch_throw()`], but the function returns a [`Result`],
+/// and the `Err` value is converted to a Java Exception that is *thrown*.
+/// 
+/// Like with a `panic!`, when the Exception is thrown this returns the [`Zeroed`][std::mem::zeroed()] representation of the return type.
+/// If the `Err` value is converted to a **null** Exception (e.g. `Err(None)`), a `java.lang.NullPointerException` is thrown instead.
+/// 
+/// This function is used by [ez_jni_macros::jni_fn].
+pub fn catch_throw_result<'local, R, E>(
+    env: &mut JNIEnv<'local>,
+    f: impl FnOnce(&mut JNIEnv<'local>) -> Result<R, E>,
+) -> R
+where E: ToException<'local> {
+    catch_throw(env, |env| match f(env) {
+        Ok(r) => r,
+        Err(err) => {
+            let mut exception = err.to_exception(env);
+            if exception.is_null() {
+                exception = env.new_string("The Err value returned by the function was converted to a null Exception")
+                    .and_then(|msg| env.new_object("java/lang/NullPointerException", "(Ljava/lang/String;)V", &[JValue::Object(&msg)]))
+                    .unwrap_or_else(|err| panic!("Failed to create NullPointerException: {err}"))
+                    .into();
+            }
+            // clear any exceptions before throwing the new exception
+            let _ = env.exception_clear();
+            env.throw(exception)
+                .unwrap_or_else(|err| panic!("Failed to throw Exception: {err}"));
+            unsafe { std::mem::zeroed() }
+        }
+    })
+}
 fn throw_panic(env: &mut JNIEnv, payload: Box<dyn Any + Send>) {
+    let panic_data = PANIC_DATA.with(|panic_data| panic_data.borrow_mut().take());
     let panic_msg = match payload.downcast::<&'static str>() {
         Ok(msg) => Some(msg.as_ref().to_string()),
         Err(payload) => match payload.downcast::<String>() {
@@ -69,15 +166,82 @@ fn throw_panic(env: &mut JNIEnv, payload: Box<dyn Any + Send>) {
             },
         },
     };
-    let msg = match (panic_msg, &*PANIC_LOCATION.read().unwrap()) {
+    let location = panic_data.as_ref().and_then(|data| data.location.as_ref());
+    let msg = match (&panic_msg, location) {
         (Some(msg), Some(info)) => format!("panicked at {info}: {msg}"),
         (Some(msg), None) => format!("panicked at unknown location: {msg}"),
         (None, Some(info)) => format!("Rust panicked at {info}, but could not obtain message"),
         (None, None) => "Rust had a panic! but could not obtain any panic data".to_string(),
     };
+    let backtrace = panic_data.as_ref()
+        .map(|data| &data.backtrace)
+        .filter(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+        .map(|backtrace| backtrace.to_string());
+
     // clear any exceptions before throwing the new exception
     let _ = env.exception_clear();
-    let _ = env.throw_new("java/lang/Exception", msg);
+
+    let class = PANIC_EXCEPTION_CLASS.read().unwrap().clone();
+    match new_panic_exception(env, &class, msg.clone(), panic_msg, location, backtrace) {
+        Some(exception) => { let _ = env.throw(exception); },
+        // Fall back to the default class if the configured class can't be constructed
+        None => {
+            let _ = env.exception_clear();
+            let _ = env.throw_new("java/lang/RuntimeException", msg);
+        },
+    }
+}
+/// Construct the Exception of **class** (see [`set_panic_exception_class()`]) and set the fields that hold the panic's data.
+/// 
+/// Returns [`None`] if the Exception could not be constructed.
+fn new_panic_exception<'local>(
+    env: &mut JNIEnv<'local>,
+    class: &str,
+    mut msg: String,
+    panic_msg: Option<String>,
+    location: Option<&PanicLocation>,
+    backtrace: Option<String>,
+) -> Option<JThrowable<'local>> {
+    let class = env.find_class(class).ok()?;
+
+    // The backtrace is put in the message if the class doesn't have a field for it
+    if let Some(backtrace) = &backtrace {
+        if !has_field(env, &class, "backtrace", "Ljava/lang/String;") {
+            msg = format!("{msg}\n{backtrace}");
+        }
+    }
+
+    let msg = env.new_string(msg).ok()?;
+    let exception = env.new_object(&class, "(Ljava/lang/String;)V", &[JValue::Object(&msg)]).ok()?;
+
+    let strings = [("panicMessage", panic_msg), ("file", location.map(|l| l.file.clone())), ("backtrace", backtrace)];
+    for (name, value) in strings {
+        if let Some(value) = value {
+            if has_field(env, &class, name, "Ljava/lang/String;") {
+                let value = env.new_string(value).ok()?;
+                env.set_field(&exception, name, "Ljava/lang/String;", JValue::Object(&value)).ok()?;
+            }
+        }
+    }
+    if let Some(location) = location {
+        for (name, value) in [("line", location.line), ("column", location.col)] {
+            if has_field(env, &class, name, "I") {
+                env.set_field(&exception, name, "I", JValue::Int(value as i32)).ok()?;
+            }
+        }
+    }
+
+    Some(exception.into())
+}
+/// Checks whether a **class** declares (or inherits) a *non-static* field with **name** and type **sig**.
+fn has_field(env: &mut JNIEnv, class: &JClass, name: &str, sig: &str) -> bool {
+    if env.get_field_id(class, name, sig).is_ok() {
+        true
+    } else {
+        // Clear the NoSuchFieldError
+        let _ = env.exception_clear();
+        false
+    }
 }
 
 /// Checks if an exception has been thrown from a previous JNI function call,
@@ -90,14 +254,15 @@ fn throw_panic(env: &mut JNIEnv, payload: Box<dyn Any + Send>) {
 /// See [`try_catch`] if panicking is not intended.
 ///
 /// This function is used by [ez_jni_macros::call!].
+#[track_caller]
 pub fn catch<'local, E: FromException<'local>>(env: &mut JNIEnv<'local>) -> Result<(), E> {
     match catch_exception(env) {
         Some(ex) => match E::from_exception(&ex, env) {
             Ok(e) => Err(e),
             Err(err) => {
                 eprintln!("Attempted to catch an exception, but failed to convert it to a concrete type:\n{err}");
-                env.throw(ex).unwrap();
-                ::std::panic::panic_any(());
+                add_rust_context(env, &ex, format!("Rust failed to convert the Exception to a concrete type: {err}"));
+                panic_exception(ex, env);
             }
         }
         None => Ok(()),
@@ -129,7 +294,10 @@ pub fn panic_exception(ex: JThrowable, env: &mut JNIEnv) -> ! {
 /// **target** is a *class* or an *object* (whose class will be determined).
 /// **method_name** is the name of the Java Method that threw the *exception*
 ///
+/// The location of the caller is added to the *exception* as a *suppressed* Exception.
+///
 /// This function is used by [ez_jni_macros::call!].
+#[track_caller]
 pub fn panic_uncaught_exception(
     env: &mut JNIEnv,
     target: Either<&str, &JObject>,
@@ -137,7 +305,7 @@ pub fn panic_uncaught_exception(
 ) {
     if let Some(ex) = catch_exception(env) {
         let class = match target {
-            Either::Left(s) => s.to_string(),
+            Either::Left(s) => crate::utils::java_path_to_dot_notation(s),
             Either::Right(obj) => env
                 .get_object_class(obj)
                 .and_then(|class| env.call_method(class, "getName", "()Ljava/lang/String;", &[]))
@@ -148,10 +316,29 @@ pub fn panic_uncaught_exception(
                 .unwrap_or_else(|_| "<Object>".to_string()),
         };
         eprintln!("Rust panic: Encountered an uncaught Java Exception after calling {class}.{}():", method_name.as_ref());
+        add_rust_context(env, &ex, format!("Rust did not catch the Exception thrown by {class}.{}()", method_name.as_ref()));
         panic_exception(ex, env);
     }
 }
 
+/// Adds a *suppressed* `RuntimeException` to **ex** (with `Throwable.addSuppressed()`)
+/// whose message is **msg** followed by the location in Rust code that called this function.
+/// 
+/// This is used before rethrowing an Exception that Rust did not handle,
+/// so that the Java stack trace shows which Rust code the Exception went through.
+/// The Exception itself is not wrapped so that Java code can still catch it by its original type.
+#[track_caller]
+fn add_rust_context(env: &mut JNIEnv, ex: &JThrowable, msg: String) {
+    let msg = format!("{msg} at {}", PanicLocation::from(std::panic::Location::caller()));
+    let result = env.new_string(msg)
+        .and_then(|msg| env.new_object("java/lang/RuntimeException", "(Ljava/lang/String;)V", &[JValue::Object(&msg)]))
+        .and_then(|context| env.call_method(ex, "addSuppressed", "(Ljava/lang/Throwable;)V", &[JValue::Object(&context)]));
+    // The original Exception is more important, so failing to add the context is ignored
+    if result.is_err() {
+        let _ = env.exception_clear();
+    }
+}
+
 /// Checks if an `Exception` was thrown after calling a Java Method, and returns said `Exception`.
 /// The `Exception` will be cleared so that it will be possible to do other jni_calls to handle the exception (e.g. rethrowing it).
 ///
@@ -16,6 +16,8 @@
 //! You can also handle `exceptions` by using `throws` after the parameters and specifying an **error type**.
 //! The syntax is very similar to [`call!`].
 //! 
+//! The [`field!`] macro can read or write the *fields* of an Object, with the same syntax as [`call!`].
+//! 
 //! ## Using Rust from Java
 //! 
 //! A Rust or C function that can be called from Java must be named in a specific way
@@ -44,6 +46,9 @@
 #[doc(hidden)]
 /// Used only by [`ez_jni_macros`]
 pub mod __throw;
+#[doc(hidden)]
+/// Used only by [`ez_jni_macros`]
+pub mod __cache;
 #[macro_use]
 pub mod utils;
 mod object;
@@ -51,3 +56,4 @@ extern crate self as ez_jni;
 
 pub use ez_jni_macros::*;
 pub use object::*;
+pub use __throw::set_panic_exception_class;
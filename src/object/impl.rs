@@ -1,4 +1,5 @@
 use ez_jni_macros::new;
+use std::{borrow::Cow, time::{Duration, SystemTime, UNIX_EPOCH}};
 use super::*;
 
 impl<'local> FromObject<'local> for JObject<'local> {
@@ -11,9 +12,16 @@ impl<'local, 'other> ToObject<'local> for JObject<'other> {
         env.new_local_ref(self).unwrap()
     }
 }
+impl JavaClass for JObject<'_> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Object")
+    }
+}
 
 impl<'local, T> FromObject<'local> for Option<T>
 where T: FromObject<'local> {
+    const CLASS: Option<&'static str> = T::CLASS;
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
         if object.is_null() {
             Ok(None)
@@ -23,19 +31,33 @@ where T: FromObject<'local> {
         }
     }
 }
+impl<T> JavaClass for Option<T>
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::class_path()
+    }
+    // The signature is not the one of T because primitives can't be null, so they are boxed
+}
 impl<'local, T> ToObject<'local> for Option<T>
 where T: ToObject<'local> {
+    const CLASS: Option<&'static str> = T::CLASS;
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         match self {
             Some(t) => t.to_object(env),
             None => JObject::null()
         }
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        T::__to_optional_array(slice, env)
+    }
 }
 
 // Implementation for String types
 
 impl FromObject<'_> for String {
+    const CLASS: Option<&'static str> = Some("java/lang/String");
+
     /// Get a [`String`] from some random Object.
     /// 
     /// Don't use this function, it only exist for compatibility.
@@ -50,12 +72,40 @@ impl FromObject<'_> for String {
         })
     }
 }
+impl JavaClass for String {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/String")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("Ljava/lang/String;")
+    }
+}
+impl JavaClass for str {
+    fn class_path() -> Cow<'static, str> {
+        String::class_path()
+    }
+    fn signature() -> Cow<'static, str> {
+        String::signature()
+    }
+}
+impl JavaClass for &str {
+    fn class_path() -> Cow<'static, str> {
+        String::class_path()
+    }
+    fn signature() -> Cow<'static, str> {
+        String::signature()
+    }
+}
 impl<'local> ToObject<'local> for String {
+    const CLASS: Option<&'static str> = Some("java/lang/String");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         self.as_str().to_object(env)
     }
 }
 impl<'local> ToObject<'local> for str {
+    const CLASS: Option<&'static str> = Some("java/lang/String");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         env.new_string(self)
             .unwrap_or_else(|err| panic!("Error converting Rust string to Java String: {err}"))
@@ -63,6 +113,8 @@ impl<'local> ToObject<'local> for str {
     }
 }
 impl<'local> ToObject<'local> for &str {
+    const CLASS: Option<&'static str> = Some("java/lang/String");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         (**self).to_object(env)
     }
@@ -70,141 +122,524 @@ impl<'local> ToObject<'local> for &str {
 
 // Implementation for number types
 
+impl JavaClass for i8 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Byte")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("B")
+    }
+}
 impl FromObject<'_> for i8 {
+    const CLASS: Option<&'static str> = Some("java/lang/Byte");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Byte", env)?;
         Ok(call!(object.byteValue() -> byte))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[B", JNIEnv::get_byte_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for i8 {
+    const CLASS: Option<&'static str> = Some("java/lang/Byte");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Byte(byte(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_byte_array,
+            JNIEnv::set_byte_array_region,
+        env)
+    }
+}
+impl JavaClass for i16 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Short")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("S")
+    }
 }
 impl FromObject<'_> for i16 {
+    const CLASS: Option<&'static str> = Some("java/lang/Short");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Short", env)?;
         Ok(call!(object.shortValue() -> short))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[S", JNIEnv::get_short_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for i16 {
+    const CLASS: Option<&'static str> = Some("java/lang/Short");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Short(short(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_short_array,
+            JNIEnv::set_short_array_region,
+        env)
+    }
+}
+impl JavaClass for i32 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Integer")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("I")
+    }
 }
 impl FromObject<'_> for i32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Integer");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Integer", env)?;
         Ok(call!(object.intValue() -> int))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[I", JNIEnv::get_int_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for i32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Integer");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Integer(int(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_int_array,
+            JNIEnv::set_int_array_region,
+        env)
+    }
+}
+impl JavaClass for i64 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Long")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("J")
+    }
 }
 impl FromObject<'_> for i64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Long");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Long", env)?;
         Ok(call!(object.longValue() -> long))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[J", JNIEnv::get_long_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for i64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Long");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Long(long(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_long_array,
+            JNIEnv::set_long_array_region,
+        env)
+    }
+}
+impl JavaClass for f32 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Float")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("F")
+    }
 }
 impl FromObject<'_> for f32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Float");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Float", env)?;
         Ok(call!(object.floatValue() -> float))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[F", JNIEnv::get_float_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for f32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Float");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Float(float(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_float_array,
+            JNIEnv::set_float_array_region,
+        env)
+    }
+}
+impl JavaClass for f64 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Double")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("D")
+    }
 }
 impl FromObject<'_> for f64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Double");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Double", env)?;
         Ok(call!(object.doubleValue() -> double))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        crate::utils::get_java_prim_array(array, "[D", JNIEnv::get_double_array_region, env)
+    }
 }
 impl<'local> ToObject<'local> for f64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Double");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Double(double(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(slice,
+            JNIEnv::new_double_array,
+            JNIEnv::set_double_array_region,
+        env)
+    }
 }
 
 // Implementation for unsigned number types
+impl JavaClass for u8 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Byte")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("B")
+    }
+}
 impl FromObject<'_> for u8 {
+    const CLASS: Option<&'static str> = Some("java/lang/Byte");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Byte", env)?;
         Ok(call!(object.byteValue() -> u8))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[B", JNIEnv::get_byte_array_region, env)?
+            .into_iter()
+            .map(|t| unsafe { std::mem::transmute(t) })
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for u8 {
+    const CLASS: Option<&'static str> = Some("java/lang/Byte");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Byte(u8(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| unsafe { std::mem::transmute(t) })
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_byte_array,
+            JNIEnv::set_byte_array_region,
+        env)
+    }
+}
+impl JavaClass for u16 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Short")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("S")
+    }
 }
 impl FromObject<'_> for u16 {
+    const CLASS: Option<&'static str> = Some("java/lang/Short");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Short", env)?;
         Ok(call!(object.shortValue() -> u16))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[S", JNIEnv::get_short_array_region, env)?
+            .into_iter()
+            .map(|t| unsafe { std::mem::transmute(t) })
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for u16 {
+    const CLASS: Option<&'static str> = Some("java/lang/Short");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Short(u16(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| unsafe { std::mem::transmute(t) })
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_short_array,
+            JNIEnv::set_short_array_region,
+        env)
+    }
+}
+impl JavaClass for u32 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Integer")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("I")
+    }
 }
 impl FromObject<'_> for u32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Integer");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Integer", env)?;
         Ok(call!(object.intValue() -> u32))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[I", JNIEnv::get_int_array_region, env)?
+            .into_iter()
+            .map(|t| unsafe { std::mem::transmute(t) })
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for u32 {
+    const CLASS: Option<&'static str> = Some("java/lang/Integer");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Integer(u32(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| unsafe { std::mem::transmute(t) })
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_int_array,
+            JNIEnv::set_int_array_region,
+        env)
+    }
+}
+impl JavaClass for u64 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Long")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("J")
+    }
 }
 impl FromObject<'_> for u64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Long");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Long", env)?;
         Ok(call!(object.longValue() -> u64))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[J", JNIEnv::get_long_array_region, env)?
+            .into_iter()
+            .map(|t| unsafe { std::mem::transmute(t) })
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for u64 {
+    const CLASS: Option<&'static str> = Some("java/lang/Long");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Long(u64(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| unsafe { std::mem::transmute(t) })
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_long_array,
+            JNIEnv::set_long_array_region,
+        env)
+    }
 }
 
 // Implementations for other primitives
 
+impl JavaClass for bool {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Boolean")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("Z")
+    }
+}
 impl FromObject<'_> for bool {
+    const CLASS: Option<&'static str> = Some("java/lang/Boolean");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Boolean", env)?;
         Ok(call!(object.booleanValue() -> boolean))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[Z", JNIEnv::get_boolean_array_region, env)?
+            .into_iter()
+            .map(|t| unsafe { std::mem::transmute(t) })
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for bool {
+    const CLASS: Option<&'static str> = Some("java/lang/Boolean");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Boolean(boolean(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| unsafe { std::mem::transmute(t) })
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_boolean_array,
+            JNIEnv::set_boolean_array_region,
+        env)
+    }
 }
 
+impl JavaClass for char {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/lang/Character")
+    }
+    fn signature() -> Cow<'static, str> {
+        Cow::Borrowed("C")
+    }
+}
 impl FromObject<'_> for char {
+    const CLASS: Option<&'static str> = Some("java/lang/Character");
+
     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
         object_check_boilerplate(object, "java/lang/Character", env)?;
         Ok(call!(object.charValue() -> char))
     }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'_>) -> Result<Vec<Self>, FromObjectError> {
+        Ok(crate::utils::get_java_prim_array(array, "[C", JNIEnv::get_char_array_region, env)?
+            .into_iter()
+            .map(|t| char::decode_utf16(Some(t))
+                .next().unwrap()
+                .unwrap_or(char::REPLACEMENT_CHARACTER)
+            )
+            .collect()
+        )
+    }
 }
 impl<'local> ToObject<'local> for char {
+    const CLASS: Option<&'static str> = Some("java/lang/Character");
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         new!(java.lang.Character(char(*self)))
     }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        crate::utils::create_java_prim_array(
+            slice.iter()
+                .map(|&t| t.encode_utf16(&mut [0;1])[0])
+                .collect::<Box<[_]>>()
+                .as_ref(),
+            JNIEnv::new_char_array,
+            JNIEnv::set_char_array_region,
+        env)
+    }
+}
+
+// Implementations for time types
+
+impl JavaClass for Duration {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/time/Duration")
+    }
+}
+/// Returns [`FromObjectError::Negative`] if the `java.time.Duration` is *negative*.
+impl FromObject<'_> for Duration {
+    const CLASS: Option<&'static str> = Some("java/time/Duration");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/time/Duration", env)?;
+        // The nanoseconds are always positive, even if the seconds are negative
+        let seconds = call!(object.getSeconds() -> long);
+        let nanos = call!(object.getNano() -> int);
+
+        match u64::try_from(seconds) {
+            Ok(seconds) => Ok(Duration::new(seconds, nanos as u32)),
+            Err(_) => Err(FromObjectError::Negative {
+                value: call!(object.toString() -> String),
+                ty: "Duration",
+            }),
+        }
+    }
+}
+/// Panics if the [`Duration`] has more seconds than [`i64::MAX`].
+impl<'local> ToObject<'local> for Duration {
+    const CLASS: Option<&'static str> = Some("java/time/Duration");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let seconds = i64::try_from(self.as_secs())
+            .unwrap_or_else(|_| panic!("The Duration {self:?} is too long for java.time.Duration"));
+        call!(static java.time.Duration.ofSeconds(long(seconds), long(self.subsec_nanos() as i64)) -> java.time.Duration)
+    }
+}
+
+impl JavaClass for SystemTime {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/time/Instant")
+    }
+}
+/// Returns [`FromObjectError::Overflow`] if the `java.time.Instant` can't be represented by a [`SystemTime`] on this platform
+/// (e.g. if it is before the *Unix epoch* on some platforms).
+impl FromObject<'_> for SystemTime {
+    const CLASS: Option<&'static str> = Some("java/time/Instant");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/time/Instant", env)?;
+        // The nanoseconds are always positive, even if the seconds are negative
+        let seconds = call!(object.getEpochSecond() -> long);
+        let nanos = Duration::from_nanos(call!(object.getNano() -> int) as u64);
+
+        let time = if seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+        };
+        time.and_then(|time| time.checked_add(nanos))
+            .ok_or_else(|| FromObjectError::Overflow {
+                value: call!(object.toString() -> String),
+                ty: "SystemTime",
+            })
+    }
+}
+/// Panics if the [`SystemTime`] is out of the range of `java.time.Instant`.
+impl<'local> ToObject<'local> for SystemTime {
+    const CLASS: Option<&'static str> = Some("java/time/Instant");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        // Instant.ofEpochSecond() adjusts the seconds if the nanoseconds are negative
+        let (seconds, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (i64::try_from(duration.as_secs()), duration.subsec_nanos() as i64),
+            Err(err) => (i64::try_from(err.duration().as_secs()).map(|seconds| -seconds), -(err.duration().subsec_nanos() as i64)),
+        };
+        let seconds = seconds.unwrap_or_else(|_| panic!("The SystemTime {self:?} is out of the range of java.time.Instant"));
+        call!(static java.time.Instant.ofEpochSecond(long(seconds), long(nanos)) -> java.time.Instant)
+    }
 }
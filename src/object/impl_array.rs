@@ -1,12 +1,13 @@
-use jni::{objects::JObjectArray, sys::jsize};
-use crate::utils::java_path_to_dot_notation;
+use std::borrow::Cow;
+use jni::{objects::{JClass, JObjectArray}, sys::jsize};
+use crate::utils::{get_object_class_name, java_path_to_dot_notation};
 
 use super::*;
 
 /// The same as [`get_object_array`], but also converts the [`JObject`]s in the array to the desired type `T`.
-fn get_t_array_from_object<'local, T>(obj: &JObject, array_class: &'static str, env: &mut JNIEnv<'local>) -> Result<Vec<T>, FromObjectError>
+pub(super) fn get_t_array_from_object<'local, T>(obj: &JObject, elem_class: Option<&str>, env: &mut JNIEnv<'local>) -> Result<Vec<T>, FromObjectError>
 where T: FromObject<'local> {
-    get_object_array(obj, Some(array_class), env).and_then(|array|
+    get_object_array(obj, elem_class, env).and_then(|array|
         Result::from_iter(
             array.into_iter()
                 .map(|obj| T::from_object(&obj, env))
@@ -16,17 +17,21 @@ where T: FromObject<'local> {
 }
 /// Creates a Rust [`Vec`] of [`JObject`]s by reading elements from a **Java Array** (**obj**).
 /// 
-/// Also checks that the Array Object's class matches the **array_class** (if it is [`Some`]).
-fn get_object_array<'local>(obj: &JObject, array_class: Option<&'static str>, env: &mut JNIEnv<'local>) -> Result<Vec<JObject<'local>>, FromObjectError> {
+/// Also checks that the Array's elements are instances of **elem_class** (if it is [`Some`]).
+fn get_object_array<'local>(obj: &JObject, elem_class: Option<&str>, env: &mut JNIEnv<'local>) -> Result<Vec<JObject<'local>>, FromObjectError> {
+    if obj.is_null() {
+        return Err(FromObjectError::Null);
+    }
+
     let array = <&JObjectArray>::from(obj);
     // Check object's type
-    if let Some(class) = array_class {
-        let class = java_path_to_dot_notation(class);
-        let obj_class = env.get_object_class(obj)
-            .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-        let obj_class = call!(obj_class.getName() -> String);
-        if obj_class != class {
-            return Err(FromObjectError::ClassMismatch { obj_class, target_class: Some(class) });
+    if let Some(elem_class) = elem_class {
+        let class = format!("[L{elem_class};");
+        if !env.is_instance_of(obj, &class).unwrap_or(false) {
+            return Err(FromObjectError::ClassMismatch {
+                obj_class: get_object_class_name(obj, env),
+                target_class: Some(java_path_to_dot_notation(&class))
+            });
         }
     }
 
@@ -46,17 +51,53 @@ fn get_object_array<'local>(obj: &JObject, array_class: Option<&'static str>, en
     Ok(vec)
 }
 
-fn create_object_array_from_t<'local, T>(slice: &[T], elem_class: &str, env: &mut JNIEnv<'local>) -> JObject<'local>
+/// Creates a **Java Array** of Objects of **elem_class** from Rust values converted with [`ToObject`].
+/// 
+/// The elements that are [`None`] are set to **null**.
+fn create_object_array_from_iter<'local, 'a, T>(iter: impl ExactSizeIterator<Item = Option<&'a T>>, elem_class: &JClass, env: &mut JNIEnv<'local>) -> JObject<'local>
+where T: ToObject<'local> + ?Sized + 'a {
+    // Allocate the array
+    let array = env.new_object_array(iter.len() as jsize, elem_class, JObject::null())
+        .unwrap_or_else(|err| panic!("Failed to create Java Object array: {err}"));
+
+    // Fill the array
+    for (i, element) in iter.enumerate() {
+        let element = match element {
+            Some(element) => element.to_object(env),
+            None => JObject::null(),
+        };
+        env.set_object_array_element(&array, i as jsize, element)
+            .unwrap_or_else(|err| panic!("Failed to set the value of Object array at index {i}: {err}"));
+    }
+
+    array.into()
+}
+/// Creates a **Java Array** of Arrays (e.g. `int[][]`) from a Rust *slice* of Arrays,
+/// where each element is converted to a Java Array with the [`ToObject`] implementation of `[T]`.
+/// 
+/// The elements that are [`None`] are set to **null**.
+fn create_array_of_arrays<'local, 'a, T>(iter: impl ExactSizeIterator<Item = Option<&'a [T]>>, env: &mut JNIEnv<'local>) -> JObject<'local>
+where T: ToObject<'local> + JavaClass + 'a {
+    // The Class of the elements is the Class of the Java Array created by `[T]`
+    let elem_class = T::array_signature();
+    let elem_class = env.find_class(elem_class.as_ref())
+        .unwrap_or_else(|err| panic!("Failed to find Class \"{elem_class}\": {err}"));
+    create_object_array_from_iter(iter, &elem_class, env)
+}
+
+/// Converts a slice of [`Option`]s to a **Java Array** of Objects of **elem_class**, where the elements that are [`None`] are **null**.
+pub(super) fn create_optional_array<'local, T>(slice: &[Option<T>], elem_class: &str, env: &mut JNIEnv<'local>) -> JObject<'local>
 where T: ToObject<'local> {
-    let obj_slice = slice.iter()
-        .map(|t| t.to_object(env))
-        .collect::<Box<_>>();
+    let elem_class = env.find_class(elem_class)
+        .unwrap_or_else(|err| panic!("Failed to find Class \"{elem_class}\": {err}"));
+    create_object_array_from_iter(slice.iter().map(Option::as_ref), &elem_class, env)
+}
 
-    create_object_array(
-        &obj_slice.iter()
-            .collect::<Box<_>>(),
-        elem_class,
-    env)
+pub(super) fn create_object_array_from_t<'local, T>(slice: &[T], elem_class: &str, env: &mut JNIEnv<'local>) -> JObject<'local>
+where T: ToObject<'local> {
+    let elem_class = env.find_class(elem_class)
+        .unwrap_or_else(|err| panic!("Failed to find Class \"{elem_class}\": {err}"));
+    create_object_array_from_iter(slice.iter().map(Some), &elem_class, env)
 }
 fn create_object_array<'local>(slice: &[&JObject], elem_class: &str, env: &mut JNIEnv<'local>) -> JObject<'local> {
     // Allocate the array
@@ -78,325 +119,131 @@ fn create_object_array<'local>(slice: &[&JObject], elem_class: &str, env: &mut J
 
 // -- Arrays --
 
-impl<'local, T> FromObject<'local> for Box<[T]>
-where Vec<T>: FromObject<'local> {
+impl<'local, T> FromObject<'local> for Vec<T>
+where T: FromObject<'local> {
     fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
-        Ok(Vec::<T>::from_object(object, env)?.into_boxed_slice())
+        if object.is_null() {
+            return Err(FromObjectError::Null);
+        }
+        // Any Java Collection can also be converted to a Vec
+        if env.is_instance_of(object, "java/util/Collection").unwrap_or(false) {
+            return super::impl_collections::get_t_collection_from_object::<T, _>(object, env);
+        }
+        T::__from_array(object, env)
     }
 }
-
-
-impl<'local> FromObject<'local> for Vec<JObject<'local>> {
+impl<'local, T> FromObject<'local> for Box<[T]>
+where Vec<T>: FromObject<'local> {
     fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
-        get_object_array(object, None, env)
-    }
-}
-/// Implementation for an Object slice paired with the element Class
-impl<'local> ToObject<'local> for (&str, [JObject<'_>]) {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array(
-            &self.1.iter()
-                .collect::<Box<_>>(),
-            self.0,
-        env)
-    }
-}
-impl<'local> ToObject<'local> for (&str, [&JObject<'_>]) {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array(&self.1, self.0, env)
+        Ok(Vec::<T>::from_object(object, env)?.into_boxed_slice())
     }
 }
 
-// Implementations for String Array
-
-impl FromObject<'_> for Vec<String> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        get_t_array_from_object(object, "[Ljava/lang/String;", env)
-    }
-}
-impl<'local> ToObject<'local> for [String] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array_from_t(self, "java/lang/String", env)
-    }
-}
-impl<'local> ToObject<'local> for [&str] {
+impl<'local, T> ToObject<'local> for [T]
+where T: ToObject<'local> {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array_from_t(self, "java/lang/String", env)
+        T::__to_array(self, env)
     }
 }
 
-// Implementation for [Option<T>]
+// The Class Path of an Array is its signature
 
-// impl FromObject<'_> for Vec<Option<JObject<'_>>> {
-//     fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-//         get_object_array(object, todo!(), env)
-//             .map(|array|
-//                 array.into_iter()
-//                     .map(|obj| if obj.is_null() { None } else { Some(obj) })
-//                     .collect()
-//             )
-//     }
-// }
-// impl<'local> ToObject<'local> for [Option<JObject<'_>>] {
-//     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-//         self.iter()
-//             .map(|opt| opt.as_ref())
-//             .collect::<Vec<_>>()
-//             .to_object(env)
-//     }
-// }
-// impl<'local> ToObject<'local> for [Option<&JObject<'_>>] {
-//     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-//         create_object_array(
-//             &self.iter()
-//                 .map(|opt| opt.as_ref()
-//                     // Copy the Object pointer. Reference should be valid throuhgout the whole function call
-//                     .map(|&o| unsafe { JObject::from_raw(o.as_raw()) })
-//                         .unwrap_or(JObject::null())
-//                 )
-//                 .collect::<Box<[_]>>(),
-//             todo!(),
-//         env)
-//     }
-// }
-
-impl FromObject<'_> for Vec<Option<String>> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        get_t_array_from_object(object, "[Ljava/lang/String;", env)
+impl<T> JavaClass for Vec<T>
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::array_signature()
     }
 }
-impl<'local> ToObject<'local> for [Option<String>] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array_from_t(self, "java/lang/String", env)
+impl<T> JavaClass for Box<[T]>
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::array_signature()
     }
 }
-impl<'local> ToObject<'local> for [Option<&str>] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        create_object_array_from_t(self, "java/lang/String", env)
+impl<T> JavaClass for [T]
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::array_signature()
     }
 }
-
-// Implementations for Number Arrays
-
-impl FromObject<'_> for Vec<i8> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_byte_array_region, env))
+impl<T, const N: usize> JavaClass for [T; N]
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::array_signature()
     }
 }
-impl<'local> ToObject<'local> for [i8] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_byte_array,
-            JNIEnv::set_byte_array_region,
-        env)
+impl<T> JavaClass for &[T]
+where T: JavaClass {
+    fn class_path() -> Cow<'static, str> {
+        T::array_signature()
     }
 }
 
-impl FromObject<'_> for Vec<i16> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_short_array_region, env))
-    }
-}
-impl<'local> ToObject<'local> for [i16] {
+/// Implementation for an Object slice paired with the element Class
+impl<'local> ToObject<'local> for (&str, [JObject<'_>]) {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_short_array,
-            JNIEnv::set_short_array_region,
+        create_object_array(
+            &self.1.iter()
+                .collect::<Box<_>>(),
+            self.0,
         env)
     }
 }
-
-impl FromObject<'_> for Vec<i32> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_int_array_region, env))
-    }
-}
-impl<'local> ToObject<'local> for [i32] {
+impl<'local> ToObject<'local> for (&str, [&JObject<'_>]) {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_int_array,
-            JNIEnv::set_int_array_region,
-        env)
+        create_object_array(&self.1, self.0, env)
     }
 }
 
-impl FromObject<'_> for Vec<i64> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_long_array_region, env))
-    }
-}
-impl<'local> ToObject<'local> for [i64] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_long_array,
-            JNIEnv::set_long_array_region,
-        env)
-    }
-}
+// Implementations for multi-dimensional Arrays.
+// The elements of these Arrays are themselves Arrays, so they override how a slice of them is converted.
+// The Class of the element Arrays is obtained from the JavaClass implementation of their elements.
 
-impl FromObject<'_> for Vec<f32> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_float_array_region, env))
-    }
-}
-impl<'local> ToObject<'local> for [f32] {
+impl<'local, T> ToObject<'local> for Vec<T>
+where T: ToObject<'local> + JavaClass {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_float_array,
-            JNIEnv::set_float_array_region,
-        env)
+        self.as_slice().to_object(env)
     }
-}
-
-impl FromObject<'_> for Vec<f64> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_double_array_region, env))
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| Some(element.as_slice())), env)
     }
-}
-impl<'local> ToObject<'local> for [f64] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(self,
-            JNIEnv::new_double_array,
-            JNIEnv::set_double_array_region,
-        env)
+    fn __to_optional_array(slice: &[Option<Self>], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| element.as_deref()), env)
     }
 }
-
-// Implementation for Unsigned Number types
-
-impl FromObject<'_> for Vec<u8> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_byte_array_region, env)
-            .into_iter()
-            .map(|t| unsafe { std::mem::transmute(t) })
-            .collect()
-        )
-    }
-}
-impl<'local> ToObject<'local> for [u8] {
+impl<'local, T> ToObject<'local> for Box<[T]>
+where T: ToObject<'local> + JavaClass {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| unsafe { std::mem::transmute(t) })
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_byte_array,
-            JNIEnv::set_byte_array_region,
-        env)
+        self.as_ref().to_object(env)
     }
-}
-
-impl FromObject<'_> for Vec<u16> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_short_array_region, env)
-            .into_iter()
-            .map(|t| unsafe { std::mem::transmute(t) })
-            .collect()
-        )
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| Some(element.as_ref())), env)
     }
-}
-impl<'local> ToObject<'local> for [u16] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| unsafe { std::mem::transmute(t) })
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_short_array,
-            JNIEnv::set_short_array_region,
-        env)
+    fn __to_optional_array(slice: &[Option<Self>], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| element.as_deref()), env)
     }
 }
-
-impl FromObject<'_> for Vec<u32> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_int_array_region, env)
-            .into_iter()
-            .map(|t| unsafe { std::mem::transmute(t) })
-            .collect()
-        )
-    }
-}
-impl<'local> ToObject<'local> for [u32] {
+impl<'local, T, const N: usize> ToObject<'local> for [T; N]
+where T: ToObject<'local> + JavaClass {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| unsafe { std::mem::transmute(t) })
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_int_array,
-            JNIEnv::set_int_array_region,
-        env)
+        self.as_slice().to_object(env)
     }
-}
-
-impl FromObject<'_> for Vec<u64> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_long_array_region, env)
-            .into_iter()
-            .map(|t| unsafe { std::mem::transmute(t) })
-            .collect()
-        )
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| Some(element.as_slice())), env)
     }
-}
-impl<'local> ToObject<'local> for [u64] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| unsafe { std::mem::transmute(t) })
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_long_array,
-            JNIEnv::set_long_array_region,
-        env)
-    }
-}
-
-// Implementations for other primitive Arrays
-
-impl FromObject<'_> for Vec<bool> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_boolean_array_region, env)
-            .into_iter()
-            .map(|t| unsafe { std::mem::transmute(t) })
-            .collect()
-        )
+    fn __to_optional_array(slice: &[Option<Self>], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|element| element.as_ref().map(<[T; N]>::as_slice)), env)
     }
 }
-impl<'local> ToObject<'local> for [bool] {
+impl<'local, T> ToObject<'local> for &[T]
+where T: ToObject<'local> + JavaClass {
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| unsafe { std::mem::transmute(t) })
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_boolean_array,
-            JNIEnv::set_boolean_array_region,
-        env)
+        (**self).to_object(env)
     }
-}
-
-impl FromObject<'_> for Vec<char> {
-    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
-        Ok(crate::utils::get_java_prim_array(object, JNIEnv::get_char_array_region, env)
-            .into_iter()
-            .map(|t| char::decode_utf16(Some(t))
-                .next().unwrap()
-                .unwrap_or(char::REPLACEMENT_CHARACTER)
-            )
-            .collect()
-        )
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().map(|&element| Some(element)), env)
     }
-}
-impl<'local> ToObject<'local> for [char] {
-    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
-        crate::utils::create_java_prim_array(
-            self.iter()
-                .map(|&t| t.encode_utf16(&mut [0;1])[0])
-                .collect::<Box<[_]>>()
-                .as_ref(),
-            JNIEnv::new_char_array,
-            JNIEnv::set_char_array_region,
-        env)
+    fn __to_optional_array(slice: &[Option<Self>], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        create_array_of_arrays(slice.iter().copied(), env)
     }
 }
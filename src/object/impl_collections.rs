@@ -0,0 +1,218 @@
+use std::{borrow::Cow, collections::{BTreeMap, HashMap, HashSet}, hash::{BuildHasher, Hash}};
+use super::*;
+
+/// Class that all Objects that can be converted to a Rust collection of elements must be a descendant of.
+const COLLECTION_BASE_PATH: &str = "java/util/Collection";
+const MAP_BASE_PATH: &str = "java/util/Map";
+
+/// Iterates over a **Java Collection** (**collection**) with its `Iterator` and converts each element with **convert**.
+///
+/// Each element's local reference is deleted once it has been converted,
+/// so that a large Collection does not overflow the *local reference table*.
+///
+/// Also checks that the Object is an instance of `java.util.Collection`.
+fn map_collection_elements<'local, T, C>(
+    collection: &JObject,
+    env: &mut JNIEnv<'local>,
+    mut convert: impl FnMut(&JObject<'local>, &mut JNIEnv<'local>) -> Result<T, FromObjectError>
+) -> Result<C, FromObjectError>
+where C: FromIterator<T> {
+    object_check_boilerplate(collection, COLLECTION_BASE_PATH, env)?;
+
+    let iterator = call!(collection.iterator() -> java.util.Iterator);
+    let result = std::iter::from_fn(|| {
+        if !call!(iterator.hasNext() -> bool) {
+            return None;
+        }
+        // Elements that are null are passed to the element type's FromObject
+        let element = call!(iterator.next() -> Option<java.lang.Object>).unwrap_or_default();
+        let result = convert(&element, env);
+        env.delete_local_ref(element).unwrap();
+        Some(result)
+    }).collect();
+    env.delete_local_ref(iterator).unwrap();
+
+    result
+}
+/// Converts the elements of a **Java Collection** to the desired type `T`.
+pub(super) fn get_t_collection_from_object<'local, T, C>(collection: &JObject, env: &mut JNIEnv<'local>) -> Result<C, FromObjectError>
+where T: FromObject<'local>,
+      C: FromIterator<T> {
+    map_collection_elements(collection, env, |element, env| T::from_object(element, env))
+}
+/// Reads the *entries* of a **Java Map** and converts the *keys* and *values* to the desired types `K` and `V`.
+fn get_t_map_from_object<'local, K, V, C>(map: &JObject, env: &mut JNIEnv<'local>) -> Result<C, FromObjectError>
+where K: FromObject<'local>,
+      V: FromObject<'local>,
+      C: FromIterator<(K, V)> {
+    object_check_boilerplate(map, MAP_BASE_PATH, env)?;
+
+    let entries = call!(map.entrySet() -> java.util.Set);
+    let result = map_collection_elements(&entries, env, |entry, env| {
+        let key = call!(entry.getKey() -> Option<java.lang.Object>).unwrap_or_default();
+        let value = call!(entry.getValue() -> Option<java.lang.Object>).unwrap_or_default();
+        let result = K::from_object(&key, env)
+            .and_then(|key| Ok((key, V::from_object(&value, env)?)));
+        env.delete_local_ref(key).unwrap();
+        env.delete_local_ref(value).unwrap();
+        result
+    });
+    env.delete_local_ref(entries).unwrap();
+
+    result
+}
+
+/// Adds the elements to a **Java Collection** (e.g. `java.util.ArrayList`), converting them with [`ToObject`].
+fn add_collection_elements<'local, 'a, T>(collection: &JObject<'local>, iter: impl Iterator<Item = &'a T>, env: &mut JNIEnv<'local>)
+where T: ToObject<'local> + 'a {
+    for element in iter {
+        let element = element.to_object(env);
+        call!(collection.add(java.lang.Object(element)) -> bool);
+        env.delete_local_ref(element).unwrap();
+    }
+}
+/// Puts the entries in a **Java Map** (e.g. `java.util.HashMap`), converting the *keys* and *values* with [`ToObject`].
+fn put_map_entries<'local, 'a, K, V>(map: &JObject<'local>, iter: impl Iterator<Item = (&'a K, &'a V)>, env: &mut JNIEnv<'local>)
+where K: ToObject<'local> + 'a,
+      V: ToObject<'local> + 'a {
+    for (key, value) in iter {
+        let key = key.to_object(env);
+        let value = value.to_object(env);
+        let previous = call!(map.put(java.lang.Object(key), java.lang.Object(value)) -> Option<java.lang.Object>);
+        env.delete_local_ref(key).unwrap();
+        env.delete_local_ref(value).unwrap();
+        if let Some(previous) = previous {
+            env.delete_local_ref(previous).unwrap();
+        }
+    }
+}
+
+/// Constructs a Collection or Map Class with an *initial capacity* of **len**,
+/// or with its no-argument constructor if **len** does not fit in an `int`.
+macro_rules! new_with_capacity {
+    ($($class:ident).+ ($len:expr), $env:ident) => {
+        match i32::try_from($len) {
+            Ok(capacity) => { let env = &mut *$env; new!($($class).+(int(capacity))) },
+            Err(_) => { let env = &mut *$env; new!($($class).+()) },
+        }
+    };
+}
+
+/// A [`Vec`] that is converted to and from a `java.util.List`, and to a `java.util.ArrayList` with [`ToObject`].
+/// See the [`ToObject`] implementation of [`Vec`] for converting to a *Java Array* instead.
+///
+/// ```
+/// # use ez_jni::{call, List};
+/// # fn example(env: &mut jni::JNIEnv) {
+/// let list = call!(static java.util.Collections.unmodifiableList(List<String>(List(vec!["a".to_string()]))) -> List<String>);
+/// assert_eq!(*list, ["a"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct List<T>(pub Vec<T>);
+newtype_wrapper!(List<T> => Vec<T>);
+
+impl<'local, T> FromObject<'local> for List<T>
+where T: FromObject<'local> {
+    const CLASS: Option<&'static str> = Some(COLLECTION_BASE_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        get_t_collection_from_object::<T, _>(object, env).map(Self)
+    }
+}
+impl<'local, T> ToObject<'local> for List<T>
+where T: ToObject<'local> {
+    const CLASS: Option<&'static str> = Some("java/util/List");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let list = new_with_capacity!(java.util.ArrayList(self.len()), env);
+        add_collection_elements(&list, self.iter(), env);
+        list
+    }
+}
+impl<T> JavaClass for List<T> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/List")
+    }
+}
+
+impl<'local, T, S> FromObject<'local> for HashSet<T, S>
+where T: FromObject<'local> + Eq + Hash,
+      S: BuildHasher + Default {
+    const CLASS: Option<&'static str> = Some(COLLECTION_BASE_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        get_t_collection_from_object::<T, _>(object, env)
+    }
+}
+/// Creates a `java.util.HashSet`.
+impl<'local, T, S> ToObject<'local> for HashSet<T, S>
+where T: ToObject<'local> {
+    const CLASS: Option<&'static str> = Some("java/util/Set");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let set = new_with_capacity!(java.util.HashSet(self.len()), env);
+        add_collection_elements(&set, self.iter(), env);
+        set
+    }
+}
+impl<T, S> JavaClass for HashSet<T, S> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/Set")
+    }
+}
+
+impl<'local, K, V, S> FromObject<'local> for HashMap<K, V, S>
+where K: FromObject<'local> + Eq + Hash,
+      V: FromObject<'local>,
+      S: BuildHasher + Default {
+    const CLASS: Option<&'static str> = Some(MAP_BASE_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        get_t_map_from_object::<K, V, _>(object, env)
+    }
+}
+/// Creates a `java.util.HashMap`.
+impl<'local, K, V, S> ToObject<'local> for HashMap<K, V, S>
+where K: ToObject<'local>,
+      V: ToObject<'local> {
+    const CLASS: Option<&'static str> = Some(MAP_BASE_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let map = new_with_capacity!(java.util.HashMap(self.len()), env);
+        put_map_entries(&map, self.iter(), env);
+        map
+    }
+}
+impl<K, V, S> JavaClass for HashMap<K, V, S> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(MAP_BASE_PATH)
+    }
+}
+
+impl<'local, K, V> FromObject<'local> for BTreeMap<K, V>
+where K: FromObject<'local> + Ord,
+      V: FromObject<'local> {
+    const CLASS: Option<&'static str> = Some(MAP_BASE_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        get_t_map_from_object::<K, V, _>(object, env)
+    }
+}
+/// Creates a `java.util.LinkedHashMap` so that the entries keep the order of the keys.
+impl<'local, K, V> ToObject<'local> for BTreeMap<K, V>
+where K: ToObject<'local>,
+      V: ToObject<'local> {
+    const CLASS: Option<&'static str> = Some(MAP_BASE_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let map = new_with_capacity!(java.util.LinkedHashMap(self.len()), env);
+        put_map_entries(&map, self.iter(), env);
+        map
+    }
+}
+impl<K, V> JavaClass for BTreeMap<K, V> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(MAP_BASE_PATH)
+    }
+}
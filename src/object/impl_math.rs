@@ -0,0 +1,151 @@
+use std::{borrow::Cow, fmt::Display};
+use super::*;
+
+const BIG_INTEGER_PATH: &str = "java/math/BigInteger";
+const BIG_DECIMAL_PATH: &str = "java/math/BigDecimal";
+
+/// Creates a [`FromObjectError::Overflow`] error for a **Java Object** that doesn't fit in the Rust type **ty**.
+fn overflow_error(object: &JObject, ty: &'static str, env: &mut JNIEnv<'_>) -> FromObjectError {
+    FromObjectError::Overflow {
+        value: call!(object.toString() -> String),
+        ty,
+    }
+}
+
+/// Reads the *two's complement* representation of a `java.math.BigInteger` in **big-endian** byte order.
+///
+/// The returned bytes are the minimum number of bytes needed to represent the value, including the sign bit.
+fn get_big_integer_bytes(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Box<[u8]>, FromObjectError> {
+    object_check_boilerplate(object, BIG_INTEGER_PATH, env)?;
+    Ok(call!(object.toByteArray() -> [u8]))
+}
+
+impl JavaClass for i128 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(BIG_INTEGER_PATH)
+    }
+}
+/// Converts from a `java.math.BigInteger`,
+/// returning [`FromObjectError::Overflow`] if the value does not fit in an [`i128`].
+impl FromObject<'_> for i128 {
+    const CLASS: Option<&'static str> = Some(BIG_INTEGER_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        let bytes = get_big_integer_bytes(object, env)?;
+        if bytes.len() > size_of::<Self>() {
+            return Err(overflow_error(object, "i128", env));
+        }
+
+        // Sign-extend the value to fill all 16 bytes
+        let negative = bytes.first().is_some_and(|&byte| (byte as i8) < 0);
+        let mut buf = [if negative { 0xFF } else { 0x00 }; size_of::<Self>()];
+        buf[size_of::<Self>() - bytes.len()..].copy_from_slice(&bytes);
+        Ok(Self::from_be_bytes(buf))
+    }
+}
+/// Creates a `java.math.BigInteger`.
+impl<'local> ToObject<'local> for i128 {
+    const CLASS: Option<&'static str> = Some(BIG_INTEGER_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        new!(java.math.BigInteger([byte](self.to_be_bytes().map(|byte| byte as i8))))
+    }
+}
+
+impl JavaClass for u128 {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(BIG_INTEGER_PATH)
+    }
+}
+/// Converts from a `java.math.BigInteger`,
+/// returning [`FromObjectError::Overflow`] if the value is *negative* or does not fit in a [`u128`].
+impl FromObject<'_> for u128 {
+    const CLASS: Option<&'static str> = Some(BIG_INTEGER_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        let bytes = get_big_integer_bytes(object, env)?;
+        if bytes.first().is_some_and(|&byte| (byte as i8) < 0) {
+            return Err(overflow_error(object, "u128", env));
+        }
+
+        // A positive value could have an extra leading zero byte for the sign bit
+        let bytes = match bytes.iter().position(|&byte| byte != 0) {
+            Some(start) => &bytes[start..],
+            None => &[],
+        };
+        if bytes.len() > size_of::<Self>() {
+            return Err(overflow_error(object, "u128", env));
+        }
+
+        let mut buf = [0; size_of::<Self>()];
+        buf[size_of::<Self>() - bytes.len()..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(buf))
+    }
+}
+/// Creates a `java.math.BigInteger`.
+impl<'local> ToObject<'local> for u128 {
+    const CLASS: Option<&'static str> = Some(BIG_INTEGER_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        // Add a leading zero byte so the most significant bit is not interpreted as the sign bit
+        let mut bytes = [0; size_of::<Self>() + 1];
+        bytes[1..].copy_from_slice(&self.to_be_bytes());
+        new!(java.math.BigInteger([byte](bytes.map(|byte| byte as i8))))
+    }
+}
+
+/// An *arbitrary-precision* decimal number that is converted to and from a `java.math.BigDecimal`.
+///
+/// The number is stored in its **decimal string** form (e.g. `"-1234.50"`) so that no precision is lost.
+/// The string can then be parsed by any decimal number type (e.g. from the `rust_decimal` or `bigdecimal` crates).
+///
+/// [`FromObject`] reads the string with `toPlainString()`, so it never has an exponent.
+/// [`ToObject`] passes the string to the `BigDecimal(String)` constructor, which also accepts an exponent (e.g. `"1.5E+3"`),
+/// and **panics** if the string is not a valid decimal number.
+///
+/// ```
+/// # use ez_jni::{call, BigDecimal};
+/// # fn example(env: &mut jni::JNIEnv) {
+/// let sum = call!(static java.math.BigDecimal.valueOf(long(1050), int(2)) -> BigDecimal);
+/// assert_eq!(sum.as_str(), "10.50");
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigDecimal(pub String);
+impl BigDecimal {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+newtype_wrapper!(BigDecimal => String);
+impl From<&str> for BigDecimal {
+    fn from(string: &str) -> Self {
+        Self(string.to_string())
+    }
+}
+impl Display for BigDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromObject<'_> for BigDecimal {
+    const CLASS: Option<&'static str> = Some(BIG_DECIMAL_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, BIG_DECIMAL_PATH, env)?;
+        Ok(Self(call!(object.toPlainString() -> String)))
+    }
+}
+impl<'local> ToObject<'local> for BigDecimal {
+    const CLASS: Option<&'static str> = Some(BIG_DECIMAL_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        new!(java.math.BigDecimal(String(self.as_str())))
+    }
+}
+impl JavaClass for BigDecimal {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(BIG_DECIMAL_PATH)
+    }
+}
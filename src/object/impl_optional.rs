@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+use super::*;
+
+/// An [`Option`] that is converted to and from a `java.util.Optional`, whose value is converted with the implementations of `T`.
+/// See the [`FromObject`] implementation of [`Option`] for converting a *nullable* Object instead.
+///
+/// ```
+/// # use ez_jni::{call, Optional, ToObject};
+/// # fn example(env: &mut jni::JNIEnv) {
+/// let value = call!(static java.util.Optional.of(java.lang.Object(3i32.to_object(env))) -> Optional<i32>);
+/// assert_eq!(*value, Some(3));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Optional<T>(pub Option<T>);
+newtype_wrapper!(Optional<T> => Option<T>);
+
+impl<'local, T> FromObject<'local> for Optional<T>
+where T: FromObject<'local> {
+    const CLASS: Option<&'static str> = Some("java/util/Optional");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/util/Optional", env)?;
+
+        if call!(object.isPresent() -> bool) {
+            let value = call!(object.get() -> java.lang.Object);
+            T::from_object(&value, env).map(|value| Self(Some(value)))
+        } else {
+            Ok(Self(None))
+        }
+    }
+}
+impl<'local, T> ToObject<'local> for Optional<T>
+where T: ToObject<'local> {
+    const CLASS: Option<&'static str> = Some("java/util/Optional");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        match &self.0 {
+            // The value could still be null (e.g. if T is an Option)
+            Some(value) => {
+                let value = value.to_object(env);
+                call!(static java.util.Optional.ofNullable(java.lang.Object(value)) -> java.util.Optional)
+            },
+            None => call!(static java.util.Optional.empty() -> java.util.Optional),
+        }
+    }
+}
+impl<T> JavaClass for Optional<T> {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/Optional")
+    }
+}
+
+/// An [`Option<i32>`] that is converted to and from a `java.util.OptionalInt`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OptionalInt(pub Option<i32>);
+newtype_wrapper!(OptionalInt => Option<i32>);
+impl FromObject<'_> for OptionalInt {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalInt");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/util/OptionalInt", env)?;
+        Ok(Self(call!(object.isPresent() -> bool)
+            .then(|| call!(object.getAsInt() -> int))
+        ))
+    }
+}
+impl<'local> ToObject<'local> for OptionalInt {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalInt");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        match self.0 {
+            Some(value) => call!(static java.util.OptionalInt.of(int(value)) -> java.util.OptionalInt),
+            None => call!(static java.util.OptionalInt.empty() -> java.util.OptionalInt),
+        }
+    }
+}
+impl JavaClass for OptionalInt {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/OptionalInt")
+    }
+}
+
+/// An [`Option<i64>`] that is converted to and from a `java.util.OptionalLong`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OptionalLong(pub Option<i64>);
+newtype_wrapper!(OptionalLong => Option<i64>);
+impl FromObject<'_> for OptionalLong {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalLong");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/util/OptionalLong", env)?;
+        Ok(Self(call!(object.isPresent() -> bool)
+            .then(|| call!(object.getAsLong() -> long))
+        ))
+    }
+}
+impl<'local> ToObject<'local> for OptionalLong {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalLong");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        match self.0 {
+            Some(value) => call!(static java.util.OptionalLong.of(long(value)) -> java.util.OptionalLong),
+            None => call!(static java.util.OptionalLong.empty() -> java.util.OptionalLong),
+        }
+    }
+}
+impl JavaClass for OptionalLong {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/OptionalLong")
+    }
+}
+
+/// An [`Option<f64>`] that is converted to and from a `java.util.OptionalDouble`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OptionalDouble(pub Option<f64>);
+newtype_wrapper!(OptionalDouble => Option<f64>);
+impl FromObject<'_> for OptionalDouble {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalDouble");
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, "java/util/OptionalDouble", env)?;
+        Ok(Self(call!(object.isPresent() -> bool)
+            .then(|| call!(object.getAsDouble() -> double))
+        ))
+    }
+}
+impl<'local> ToObject<'local> for OptionalDouble {
+    const CLASS: Option<&'static str> = Some("java/util/OptionalDouble");
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        match self.0 {
+            Some(value) => call!(static java.util.OptionalDouble.of(double(value)) -> java.util.OptionalDouble),
+            None => call!(static java.util.OptionalDouble.empty() -> java.util.OptionalDouble),
+        }
+    }
+}
+impl JavaClass for OptionalDouble {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed("java/util/OptionalDouble")
+    }
+}
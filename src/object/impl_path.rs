@@ -0,0 +1,106 @@
+use std::{borrow::Cow, path::{Path, PathBuf}};
+use super::*;
+
+const FILE_PATH: &str = "java/io/File";
+const NIO_PATH_PATH: &str = "java/nio/file/Path";
+
+/// Gets the [`str`] of a Rust [`Path`] so that it can be passed to Java.
+///
+/// Java Strings can only hold *Unicode* text, but a Rust [`Path`] could be any sequence of bytes (on Unix),
+/// so this **panics** if the path is not valid *UTF-8* instead of silently converting it to a different path.
+fn path_to_str(path: &Path) -> &str {
+    path.to_str()
+        .unwrap_or_else(|| panic!("The path {path:?} is not valid UTF-8, so it can't be converted to a Java String"))
+}
+
+/// Converts from either a `java.io.File` (with `getPath()`) or a `java.nio.file.Path` (with `toString()`).
+impl FromObject<'_> for PathBuf {
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        if !object.is_null() && env.is_instance_of(object, NIO_PATH_PATH).unwrap_or(false) {
+            return Ok(PathBuf::from(call!(object.toString() -> String)));
+        }
+
+        object_check_boilerplate(object, FILE_PATH, env)?;
+        Ok(PathBuf::from(call!(object.getPath() -> String)))
+    }
+}
+impl JavaClass for PathBuf {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(FILE_PATH)
+    }
+}
+impl JavaClass for Path {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(FILE_PATH)
+    }
+}
+impl JavaClass for &Path {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(FILE_PATH)
+    }
+}
+/// Creates a `java.io.File`.
+///
+/// **Panics** if the path is not valid *UTF-8*.
+impl<'local> ToObject<'local> for PathBuf {
+    const CLASS: Option<&'static str> = Some(FILE_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        self.as_path().to_object(env)
+    }
+}
+/// Creates a `java.io.File`.
+///
+/// **Panics** if the path is not valid *UTF-8*.
+impl<'local> ToObject<'local> for Path {
+    const CLASS: Option<&'static str> = Some(FILE_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        new!(java.io.File(String(path_to_str(self))))
+    }
+}
+impl<'local> ToObject<'local> for &Path {
+    const CLASS: Option<&'static str> = Some(FILE_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        (**self).to_object(env)
+    }
+}
+
+/// A [`PathBuf`] that is converted to and from a `java.nio.file.Path`.
+/// See the [`ToObject`] implementation of [`PathBuf`] for converting to a `java.io.File` instead.
+///
+/// [`ToObject`] **panics** if the path is not valid *UTF-8*, because Java Strings can only hold *Unicode* text.
+///
+/// ```
+/// # use ez_jni::{call, NioPath};
+/// # fn example(env: &mut jni::JNIEnv) {
+/// let path = call!(static java.nio.file.Paths.get(String("/tmp"), [String]([] as [&str; 0])) -> NioPath);
+/// assert_eq!(path.as_os_str(), "/tmp");
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NioPath(pub PathBuf);
+newtype_wrapper!(NioPath => PathBuf);
+
+impl FromObject<'_> for NioPath {
+    const CLASS: Option<&'static str> = Some(NIO_PATH_PATH);
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'_>) -> Result<Self, FromObjectError> {
+        object_check_boilerplate(object, NIO_PATH_PATH, env)?;
+        Ok(Self(PathBuf::from(call!(object.toString() -> String))))
+    }
+}
+impl<'local> ToObject<'local> for NioPath {
+    const CLASS: Option<&'static str> = Some(NIO_PATH_PATH);
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        let file = self.0.to_object(env);
+        call!(file.toPath() -> java.nio.file.Path)
+    }
+}
+impl JavaClass for NioPath {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(NIO_PATH_PATH)
+    }
+}
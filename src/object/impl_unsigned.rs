@@ -0,0 +1,219 @@
+use std::{borrow::Cow, fmt::Display};
+use crate::utils::__Primitive;
+use super::*;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+}
+
+/// A Rust *unsigned integer* that can be used with [`Checked`] and [`Widened`].
+///
+/// It specifies the **Java types** that the integer is read from and converted to.
+pub trait UnsignedInt
+where Self: Copy + Display + sealed::Sealed
+    + TryFrom<Self::Signed> + TryFrom<Self::Wide>,
+{
+    /// The *signed* Java type with the same size (e.g. `int` for [`u32`]).
+    type Signed: for<'local> FromObject<'local> + for<'local> ToObject<'local> + JavaClass
+        + Copy + Into<i128> + TryFrom<Self>;
+    /// The *signed* Java type that can hold all values of this type (e.g. `long` for [`u32`]).
+    type Wide: for<'local> FromObject<'local> + for<'local> ToObject<'local> + JavaClass
+        + Copy + Into<i128> + From<Self>;
+    /// The name of the Rust type, used in error messages.
+    const NAME: &'static str;
+}
+impl UnsignedInt for u8 {
+    type Signed = i8;
+    type Wide = i16;
+    const NAME: &'static str = "u8";
+}
+impl UnsignedInt for u16 {
+    type Signed = i16;
+    type Wide = i32;
+    const NAME: &'static str = "u16";
+}
+impl UnsignedInt for u32 {
+    type Signed = i32;
+    type Wide = i64;
+    const NAME: &'static str = "u32";
+}
+/// [`Widened`] converts to and from a `java.math.BigInteger`.
+impl UnsignedInt for u64 {
+    type Signed = i64;
+    type Wide = i128;
+    const NAME: &'static str = "u64";
+}
+
+/// Converts a Java integer (**value**) to the unsigned integer `T`,
+/// returning [`FromObjectError::Negative`] or [`FromObjectError::Overflow`] if it is out of range.
+fn to_unsigned<T, J>(value: J) -> Result<T, FromObjectError>
+where T: UnsignedInt + TryFrom<J>,
+      J: Into<i128> + Copy {
+    T::try_from(value).map_err(|_| {
+        let value = value.into();
+        if value < 0 {
+            FromObjectError::Negative { value: value.to_string(), ty: T::NAME }
+        } else {
+            FromObjectError::Overflow { value: value.to_string(), ty: T::NAME }
+        }
+    })
+}
+
+/// An *unsigned integer* that is **checked** when it is converted to and from the *signed* Java type with the same size
+/// (e.g. `int` or `java.lang.Integer` for [`u32`]).
+///
+/// The [`FromObject`] and [`ToObject`] implementations of the unsigned integers themselves *reinterpret the bits* of the value,
+/// so a Java `-1` becomes [`u32::MAX`].
+/// Instead, [`FromObject`] for this type returns [`FromObjectError::Negative`] if the Java value is *negative*,
+/// and [`ToObject`] **panics** if the Rust value is too large for the Java type (e.g. greater than [`i32::MAX`]).
+///
+/// Slices and [`Vec`]s of this type are converted to and from *primitive Arrays* (e.g. `int[]`), and every element is checked.
+///
+/// In [`call!`] and [`new!`], this type is converted to and from the *primitive* (e.g. `int`) instead of the boxed Class,
+/// and the call **panics** with the [`FromObjectError`] if the returned value is negative.
+///
+/// ```
+/// # use ez_jni::{call, Checked};
+/// # fn example(list: &jni::objects::JObject, env: &mut jni::JNIEnv) {
+/// let size = call!(list.size() -> Checked<u32>);
+/// let last = call!(list.get(Checked<u32>(Checked(*size - 1))) -> java.lang.Object);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<T: UnsignedInt>(pub T);
+newtype_wrapper!(Checked<T: UnsignedInt> => T; wrap_only);
+impl<T: UnsignedInt> Checked<T> {
+    /// Converts the value to the *signed* Java type, or **panics** if it is too large.
+    fn to_signed(self) -> T::Signed {
+        T::Signed::try_from(self.0)
+            .unwrap_or_else(|_| panic!("The value {} does not fit in the Java type {}", self.0, T::Signed::class_path()))
+    }
+}
+
+impl<'local, T: UnsignedInt> FromObject<'local> for Checked<T> {
+    const CLASS: Option<&'static str> = <T::Signed as FromObject<'local>>::CLASS;
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        to_unsigned(T::Signed::from_object(object, env)?).map(Self)
+    }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'local>) -> Result<Vec<Self>, FromObjectError> {
+        T::Signed::__from_array(array, env)?
+            .into_iter()
+            .map(|value| to_unsigned(value).map(Self))
+            .collect()
+    }
+}
+impl<'local, T: UnsignedInt> ToObject<'local> for Checked<T> {
+    const CLASS: Option<&'static str> = <T::Signed as ToObject<'local>>::CLASS;
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        self.to_signed().to_object(env)
+    }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        T::Signed::__to_array(
+            &slice.iter()
+                .map(|value| value.to_signed())
+                .collect::<Box<[_]>>(),
+        env)
+    }
+}
+/// The signature is that of the *boxed* Class (e.g. `Ljava/lang/Integer;`),
+/// but the signature of an Array is that of the *primitive* Array (e.g. `[I`).
+impl<T: UnsignedInt> JavaClass for Checked<T> {
+    fn class_path() -> Cow<'static, str> {
+        T::Signed::class_path()
+    }
+    fn array_signature() -> Cow<'static, str> {
+        T::Signed::array_signature()
+    }
+}
+impl<T: UnsignedInt> __Primitive for Checked<T> {
+    type Primitive = T::Signed;
+
+    fn __from_primitive(value: Self::Primitive) -> Result<Self, FromObjectError> {
+        to_unsigned(value).map(Self)
+    }
+    fn __to_primitive(&self) -> Self::Primitive {
+        self.to_signed()
+    }
+}
+
+/// An *unsigned integer* that is converted to and from a **wider** *signed* Java type that can hold all of its values
+/// (e.g. `long` or `java.lang.Long` for [`u32`], like the result of `Integer.toUnsignedLong()`).
+///
+/// The Java types are `short` for [`u8`], `int` for [`u16`], `long` for [`u32`], and `java.math.BigInteger` for [`u64`].
+/// [`FromObject`] returns [`FromObjectError::Negative`] or [`FromObjectError::Overflow`] if the Java value is out of the range of the Rust type,
+/// and [`ToObject`] never fails.
+///
+/// Slices and [`Vec`]s of this type are converted to and from *primitive Arrays* of the wider type (e.g. `long[]`), and every element is checked.
+///
+/// Like [`Checked`], this type is converted to and from the *primitive* (e.g. `long`) in [`call!`] and [`new!`].
+///
+/// ```
+/// # use ez_jni::{call, Widened};
+/// # fn example(env: &mut jni::JNIEnv) {
+/// let value = call!(static java.lang.Integer.toUnsignedLong(int(-1)) -> Widened<u32>);
+/// assert_eq!(*value, u32::MAX);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Widened<T: UnsignedInt>(pub T);
+newtype_wrapper!(Widened<T: UnsignedInt> => T; wrap_only);
+
+impl<'local, T: UnsignedInt> FromObject<'local> for Widened<T> {
+    const CLASS: Option<&'static str> = <T::Wide as FromObject<'local>>::CLASS;
+
+    fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError> {
+        let value = T::Wide::from_object(object, env)
+            // The value could also be too large for the wide type (e.g. a BigInteger)
+            .map_err(|err| match err {
+                FromObjectError::Overflow { value, .. } => FromObjectError::Overflow { value, ty: T::NAME },
+                err => err,
+            })?;
+        to_unsigned(value).map(Self)
+    }
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'local>) -> Result<Vec<Self>, FromObjectError> {
+        T::Wide::__from_array(array, env)?
+            .into_iter()
+            .map(|value| to_unsigned(value).map(Self))
+            .collect()
+    }
+}
+impl<'local, T: UnsignedInt> ToObject<'local> for Widened<T> {
+    const CLASS: Option<&'static str> = <T::Wide as ToObject<'local>>::CLASS;
+
+    fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
+        T::Wide::from(self.0).to_object(env)
+    }
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local> {
+        T::Wide::__to_array(
+            &slice.iter()
+                .map(|value| T::Wide::from(value.0))
+                .collect::<Box<[_]>>(),
+        env)
+    }
+}
+/// The signature is that of the *boxed* Class (e.g. `Ljava/lang/Long;`),
+/// but the signature of an Array is that of the *primitive* Array (e.g. `[J`).
+impl<T: UnsignedInt> JavaClass for Widened<T> {
+    fn class_path() -> Cow<'static, str> {
+        T::Wide::class_path()
+    }
+    fn array_signature() -> Cow<'static, str> {
+        T::Wide::array_signature()
+    }
+}
+impl<T: UnsignedInt> __Primitive for Widened<T> {
+    type Primitive = T::Wide;
+
+    fn __from_primitive(value: Self::Primitive) -> Result<Self, FromObjectError> {
+        to_unsigned(value).map(Self)
+    }
+    fn __to_primitive(&self) -> Self::Primitive {
+        T::Wide::from(self.0)
+    }
+}
@@ -1,14 +1,60 @@
+/// Implements [`Deref`](std::ops::Deref) and [`DerefMut`](std::ops::DerefMut) to the inner value of a *newtype* wrapper,
+/// and [`From`] to wrap the inner value.
+///
+/// Also implements [`From`] to unwrap the inner value, unless `wrap_only` is passed
+/// (e.g. when the inner type is a generic, which is not allowed by the orphan rule).
+macro_rules! newtype_wrapper {
+    ($wrapper:ident$(<$($generic:ident $(: $bound:path)?),*>)? => $inner:ty) => {
+        newtype_wrapper!($wrapper$(<$($generic $(: $bound)?),*>)? => $inner; wrap_only);
+        impl$(<$($generic $(: $bound)?),*>)? From<$wrapper$(<$($generic),*>)?> for $inner {
+            fn from(wrapper: $wrapper$(<$($generic),*>)?) -> Self {
+                wrapper.0
+            }
+        }
+    };
+    ($wrapper:ident$(<$($generic:ident $(: $bound:path)?),*>)? => $inner:ty; wrap_only) => {
+        impl$(<$($generic $(: $bound)?),*>)? ::std::ops::Deref for $wrapper$(<$($generic),*>)? {
+            type Target = $inner;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl$(<$($generic $(: $bound)?),*>)? ::std::ops::DerefMut for $wrapper$(<$($generic),*>)? {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+        impl$(<$($generic $(: $bound)?),*>)? From<$inner> for $wrapper$(<$($generic),*>)? {
+            fn from(inner: $inner) -> Self {
+                Self(inner)
+            }
+        }
+    };
+}
+
 mod r#impl;
 mod impl_array;
+mod impl_collections;
+mod impl_optional;
+mod impl_math;
+mod impl_unsigned;
+mod impl_path;
+
+pub use impl_collections::List;
+pub use impl_optional::{Optional, OptionalInt, OptionalLong, OptionalDouble};
+pub use impl_math::BigDecimal;
+pub use impl_unsigned::{Checked, Widened, UnsignedInt};
+pub use impl_path::NioPath;
 
 use either::Either;
 use jni::{JNIEnv, objects::{JObject, JThrowable, JValue}};
 use thiserror::Error;
-use std::io;
-use ez_jni_macros::call;
-use crate::__throw::panic_uncaught_exception;
+use std::{borrow::Cow, io};
+use ez_jni_macros::{call, new};
+use crate::{__throw::panic_uncaught_exception, utils::get_object_class_name};
 
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum FromObjectError {
     #[error("Object can't be NULL")]
     Null,
@@ -19,6 +65,10 @@ pub enum FromObjectError {
     ClassMismatch { obj_class: String, target_class: Option<String> },
     #[error("Could not find field {name:?} of type {ty} in class {target_class}; maybe its private?")]
     FieldNotFound { name: String, ty: String, target_class: String },
+    #[error("The value {value} does not fit in the Rust type {ty}")]
+    Overflow { value: String, ty: &'static str },
+    #[error("The value {value} is negative, but the Rust type {ty} can't be negative")]
+    Negative { value: String, ty: &'static str },
     // #[error("{0}")]
     // Other(String)
 }
@@ -64,18 +114,151 @@ pub enum FromObjectError {
 /// ```
 pub trait FromObject<'local>
 where Self: Sized {
+    /// The *Class* of the Objects that this type is converted from (e.g. `java/lang/String`),
+    /// or [`None`] if it can be converted from Objects of different Classes.
+    /// 
+    /// It is used to check the Class of a *Java Array* that is converted to a [`Vec`] of this type.
+    /// The derive macro uses the `class` attribute of the *struct* or *enum*.
+    const CLASS: Option<&'static str> = None;
+
     /// Construct a [`Self`] by reading data from a *Java Object*.
     /// Will [`panic!`] if any of the underlying JNI calls fail.
     fn from_object(object: &JObject, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError>;
+
+    /// Reads the elements of a *Java Array* that is not **null**.
+    /// 
+    /// This is used by the implementation of [`FromObject`] for [`Vec<Self>`],
+    /// and only needs to be overriden by types that are read from *primitive Arrays* (e.g. `int[]`).
+    #[doc(hidden)]
+    fn __from_array(array: &JObject, env: &mut JNIEnv<'local>) -> Result<Vec<Self>, FromObjectError> {
+        impl_array::get_t_array_from_object(array, Self::CLASS, env)
+    }
 }
 
+/// Allows converting a Rust type to a *Java Object* by constructing the Object with the Rust type's data.
+///
+/// ### Derive
+/// This trait has a **derive macro** available from [`ez_jni_macros`].
+/// It uses the same attributes as [`FromObject`], so both traits can be derived for the same type.
+/// Use it on *structs* to construct an Object of 1 specific Class.
+/// Use it on *enums* to construct an Object of a different Class for each variant.
+///
+/// By default, the Object is created with the Class' *default constructor* (one that takes no arguments),
+/// and then each field of the *struct* is assigned to a *member* of the Object.
+/// If the Object has no member with the field's name, the *setter method* for it is called instead (e.g. `setMessage(value)`).
+///
+/// **Attributes**:
+/// - **`class`**: Specifies the **Class Path** of the Object that will be constructed (*optional* for the enum item).
+/// - **`constructor`**: Pass all the fields to the Class' *constructor* (in the order they were declared)
+///   instead of assigning them after construction.
+///   Can be used on the *struct* or *enum variant*.
+/// - **`field`**: Specify certain properties to control how a *struct's field* is assigned to an *Object's member*.
+///   - **`name`**: Assign the Object's field with this name instead of the field's name (does not fall back to the setter method).
+///   - **`call`**: Instead of assigning a field, call the *setter method* that corresponds to this *getter method*.
+///     E.g. `getMessage` will call `setMessage(value)`.
+///   - **`class`**: The Class of the Object that the struct field's type is converted to with [`ToObject`].
+///     This property is required for *non-primitives*.
+///
+/// ```
+/// # use ez_jni::ToObject;
+///
+/// #[derive(ToObject)]
+/// #[class(me.author.MyClass)]
+/// struct MyClass {
+///     // Implicitly assigns the field "message"
+///     message: String
+/// }
+///
+/// #[derive(ToObject)]
+/// #[class(me.author.MyClass)] // Optional
+/// enum MyClasses {
+///     #[class(me.author.MyClassDescendant)]
+///     Descendant { message: String },
+///     #[class(me.author.MyOtherClass)]
+///     #[constructor]
+///     Other(String),
+///     #[class(me.author.MyFinalClass)]
+///     Final(#[field(call = getMessage)] String),
+/// }
+/// ```
 pub trait ToObject<'local> {
+    /// The *Class* of the Objects that this type is converted to (e.g. `java/lang/String`),
+    /// or [`None`] if it can be converted to Objects of different Classes.
+    /// 
+    /// It is used as the *element Class* of the *Java Array* that a slice of this type is converted to.
+    /// If it is [`None`], the Array is an `Object[]`.
+    /// The derive macro uses the `class` attribute of the *struct* or *enum*.
+    const CLASS: Option<&'static str> = None;
+
     /// Create an instance of a Class by constructing an object from data in a *Rust struct*.
     /// Will [`panic!`] if any of the underlying JNI calls fail.
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local>;
+
+    /// Creates a *Java Array* from a slice of this type.
+    /// 
+    /// This is used by the implementation of [`ToObject`] for `[Self]`,
+    /// and only needs to be overriden by types that are converted to *primitive Arrays* (e.g. `int[]`)
+    /// or to Arrays whose element Class is not [`CLASS`][ToObject::CLASS].
+    #[doc(hidden)]
+    fn __to_array(slice: &[Self], env: &mut JNIEnv<'local>) -> JObject<'local>
+    where Self: Sized {
+        impl_array::create_object_array_from_t(slice, Self::CLASS.unwrap_or("java/lang/Object"), env)
+    }
+    /// Creates a *Java Array* from a slice of [`Option`]s of this type, where the elements that are [`None`] are **null**.
+    /// 
+    /// This is used by the implementation of [`ToObject`] for `[Option<Self>]`,
+    /// and only needs to be overriden by types that are converted to Objects whose Class is not [`CLASS`][ToObject::CLASS] (e.g. Arrays).
+    #[doc(hidden)]
+    fn __to_optional_array(slice: &[Option<Self>], env: &mut JNIEnv<'local>) -> JObject<'local>
+    where Self: Sized {
+        impl_array::create_optional_array(slice, Self::CLASS.unwrap_or("java/lang/Object"), env)
+    }
 }
 
 
+/// Gives the *Java type* that a Rust type is converted to and from,
+/// so that generic code can know it without having an Object.
+/// 
+/// The **class path** is in the same format used by [`JNIEnv::find_class()`] (e.g. `java/lang/String`),
+/// so the class path of an *Array* is its **signature** (e.g. `[I`).
+/// *Primitives* use their boxed Class (e.g. `java/lang/Integer` for [`i32`]),
+/// but their signature is the primitive type's signature (e.g. `I`).
+/// 
+/// ### Derive
+/// This trait has a **derive macro** available from [`ez_jni_macros`].
+/// It uses the same `class` attribute as [`FromObject`] and [`ToObject`],
+/// which is *required* on the enum item because it is the Class of all the variants.
+/// 
+/// ```
+/// # use ez_jni::{FromObject, JavaClass};
+/// #[derive(FromObject, JavaClass)]
+/// #[class(me.author.MyClass)]
+/// struct MyClass {
+///     message: String
+/// }
+/// 
+/// assert_eq!(MyClass::class_path(), "me/author/MyClass");
+/// assert_eq!(MyClass::signature(), "Lme/author/MyClass;");
+/// assert_eq!(Vec::<MyClass>::array_signature(), "[[Lme/author/MyClass;");
+/// ```
+pub trait JavaClass {
+    /// The *Class Path* of the Java type, with components separated by *slashes* (e.g. `java/lang/String`).
+    fn class_path() -> Cow<'static, str>;
+    /// The *JNI signature* of the Java type (e.g. `Ljava/lang/String;`).
+    fn signature() -> Cow<'static, str> {
+        let class_path = Self::class_path();
+        if class_path.starts_with('[') {
+            class_path
+        } else {
+            Cow::Owned(format!("L{class_path};"))
+        }
+    }
+    /// The *JNI signature* of a Java Array whose elements are this type (e.g. `[Ljava/lang/String;`).
+    fn array_signature() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}", Self::signature()))
+    }
+}
+
 
 /// Allows converting *Java Exceptions* to Rust types that allow for better error handling.
 /// 
@@ -108,18 +291,63 @@ where Self: Sized {
     fn from_exception(exception: &JThrowable, env: &mut JNIEnv<'local>) -> Result<Self, FromObjectError>;
 }
 
+/// Allows converting Rust types (usually errors) to *Java Exceptions* so they can be **thrown**.
+/// This is the inverse of [`FromException`].
+/// 
+/// A [`jni_fn`][ez_jni_macros::jni_fn] can return a [`Result`] whose `Err` type implements this trait,
+/// and the `Err` value will be thrown as the Exception returned by [`to_exception()`][ToException::to_exception()].
+/// 
+/// # Derive
+/// This trait has a **derive macro** available from [`ez_jni_macros`].
+/// The type must implement [`Display`][std::fmt::Display], which is used as the Exception's **message**.
+/// 
+/// **Attributes**:
+/// - **`class`**: Specifies the **Class Path** of the Exception created by the *struct or enum variant*.
+///   The Class must have a constructor that takes a `java.lang.String` (the message).
+/// - **`cause`**: Put on a *field* (that implements [`ToException`]) to use it as the *cause* of the Exception.
+///   The cause is **null** if the field is an [`Option`] that is [`None`].
+///   The Class must have a constructor that takes a `java.lang.String` and a `java.lang.Throwable`.
+/// 
+/// ```
+/// # use ez_jni::ToException;
+/// #[derive(ToException)]
+/// #[class(java.lang.IllegalStateException)]
+/// struct MyStError;
+/// impl std::fmt::Display for MyStError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str("Something went wrong")
+///     }
+/// }
+/// 
+/// #[derive(ToException)]
+/// enum MyEnmError {
+///     #[class(java.lang.IllegalArgumentException)]
+///     InvalidArg(String),
+///     #[class(java.io.IOException)]
+///     Io(#[cause] std::io::Error),
+/// }
+/// impl std::fmt::Display for MyEnmError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Self::InvalidArg(arg) => write!(f, "Invalid argument: {arg}"),
+///             Self::Io(err) => write!(f, "IO error: {err}"),
+///         }
+///     }
+/// }
+/// ```
+pub trait ToException<'local> {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local>;
+}
+
 /// Does the required checks to ensure that a Java Object is valid.
 fn object_check_boilerplate(object: &JObject, path: &'static str, env: &mut JNIEnv) -> Result<(), FromObjectError> {
     if object.is_null() {
         return Err(FromObjectError::Null)
     }
-    
-    let obj_class = env.get_object_class(object)
-        .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-    
+
     if !env.is_instance_of(object, path).unwrap() {
         return Err(FromObjectError::ClassMismatch {
-            obj_class: call!(obj_class.getName() -> String),
+            obj_class: get_object_class_name(object, env),
             target_class: Some(path.to_string())
         })
     }
@@ -139,10 +367,42 @@ impl FromException<'_> for String {
     }
 }
 
+impl<'local, 'other> ToException<'local> for JThrowable<'other> {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        env.new_local_ref(self).unwrap().into()
+    }
+}
+/// Is **null** if [`None`], which is only valid for an Exception's *cause*.
+/// 
+/// Returning `Err(None)` from a `jni_fn` throws a `java.lang.NullPointerException`.
+impl<'local, T> ToException<'local> for Option<T>
+where T: ToException<'local> {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        match self {
+            Some(t) => t.to_exception(env),
+            None => JObject::null().into()
+        }
+    }
+}
+
+/// Throws a `java.lang.RuntimeException` with the String as the message.
+impl<'local> ToException<'local> for String {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        self.as_str().to_exception(env)
+    }
+}
+impl<'local> ToException<'local> for &str {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        new!(java.lang.RuntimeException(String(self))).into()
+    }
+}
+
 /// Class that all Objects that can be converted to [`std::io::Error`] must be a descendant of.
-static IO_ERROR_BASE_PATH: &str = "java/io/IOException";
+const IO_ERROR_BASE_PATH: &str = "java/io/IOException";
 
 impl FromObject<'_> for std::io::Error {
+    const CLASS: Option<&'static str> = Some(IO_ERROR_BASE_PATH);
+
     fn from_object(object: &JObject, env: &mut JNIEnv) -> Result<Self, FromObjectError> {
         static MAP: &[(&str, io::ErrorKind)] = &[
             ("java/io/FileNotFoundException", io::ErrorKind::NotFound),
@@ -199,7 +459,14 @@ impl FromObject<'_> for std::io::Error {
         Ok(Self::other(format!("{class_str}: {msg}")))
     }
 }
+impl JavaClass for std::io::Error {
+    fn class_path() -> Cow<'static, str> {
+        Cow::Borrowed(IO_ERROR_BASE_PATH)
+    }
+}
 impl<'local> ToObject<'local> for std::io::Error {
+    const CLASS: Option<&'static str> = Some(IO_ERROR_BASE_PATH);
+
     fn to_object(&self, env: &mut JNIEnv<'local>) -> JObject<'local> {
         static MAP: &[(io::ErrorKind, &str)] = &[
             (io::ErrorKind::NotFound, "java/io/FileNotFoundException"),
@@ -215,7 +482,7 @@ impl<'local> ToObject<'local> for std::io::Error {
             (io::ErrorKind::AlreadyExists, "java/nio/file/FileAlreadyExistsException"),
             // (io::ErrorKind::WouldBlock),
             (io::ErrorKind::InvalidInput, "java/lang/IllegalArgumentException"),
-            (io::ErrorKind::InvalidData, "java/lang/IllegalFormatException"),
+            (io::ErrorKind::InvalidData, "java/io/UTFDataFormatException"),
             (io::ErrorKind::TimedOut, "java/net/SocketTimeoutException"),
             (io::ErrorKind::TimedOut, "org/apache/http/conn/ConnectTimeoutException"),
             (io::ErrorKind::WriteZero, "java/io/WriteAbortedException"),
@@ -232,7 +499,7 @@ impl<'local> ToObject<'local> for std::io::Error {
 
         let msg = self.to_string().to_object(env);
 
-        env.new_object(class, "(Ljava.lang.String;)V", &[
+        env.new_object(class, "(Ljava/lang/String;)V", &[
             JValue::Object(&msg)
         ])
             .unwrap_or_else(|err| {
@@ -247,3 +514,8 @@ impl FromException<'_> for std::io::Error {
         <Self as FromObject>::from_object(exception, env)
     }
 }
+impl<'local> ToException<'local> for std::io::Error {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        <Self as ToObject>::to_object(self, env).into()
+    }
+}
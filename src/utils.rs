@@ -1,7 +1,8 @@
 use jni::{
-    errors::Error as JNIError, objects::{JObject, JPrimitiveArray, JString, JValueOwned}, sys::jsize, JNIEnv
+    errors::Error as JNIError, objects::{JClass, JMethodID, JObject, JPrimitiveArray, JString, JValue, JValueOwned}, signature::{Primitive, ReturnType}, sys::{jsize, jvalue}, JNIEnv
 };
-use crate::{call, object::FromObjectError, FromException, __throw::{panic_exception, try_catch}};
+use std::borrow::Cow;
+use crate::{call, object::FromObjectError, FromException, JavaClass, ToObject, __throw::{panic_exception, try_catch}};
 
 #[cfg(target_os = "android")]
 pub use android::*;
@@ -47,6 +48,14 @@ pub fn get_nullable_string(arg: JString, env: &mut JNIEnv) -> Option<String> {
             .map(String::from)
     }
 }
+/// Gets the name of the *Class* of **object** (e.g. `java.lang.String`).
+/// 
+/// This is mostly used to build error messages, so the name is only looked up when it's needed.
+pub fn get_object_class_name(object: &JObject, env: &mut JNIEnv) -> String {
+    let class = env.get_object_class(object)
+        .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
+    call!(class.getName() -> String)
+}
 
 /// Read a **field** from a Java Object, given the **name** of the field and its **ty**pe.
 /// 
@@ -128,6 +137,84 @@ pub fn call_getter<'local>(
         })
 }
 
+/// Write a **value** to a **field** of a Java Object, given the **name** of the field and its **ty**pe.
+/// 
+/// If the field does not exists and **setter_fallback** is `true`,
+/// this will call a *setter method* with `set` prepended to the field's name.
+/// E.g. if the field `java.lang.String message` did not exist,
+/// then `void setMessage(java.lang.String)` will be called.
+pub fn set_field(
+    object: &JObject,
+    name: &str,
+    ty: &str,
+    value: JValue,
+    setter_fallback: bool,
+    env: &mut JNIEnv,
+) -> Result<(), FromObjectError> {
+    #[derive(FromException)]
+    #[class(java.lang.NoSuchFieldError)]
+    struct FieldNotFound;
+
+    // What to do if FieldNotFound
+    let handle_not_found = |env: &mut JNIEnv| {
+        if setter_fallback {
+            let method = format!("set{}", first_char_uppercase(name));
+            call_setter(object, &method, ty, value, env)
+        } else {
+            Err(FromObjectError::FieldNotFound {
+                name: name.to_string(),
+                ty: ty.to_string(),
+                target_class: get_object_class_name(object, env)
+            })
+        }
+    };
+
+    env.set_field(object, name, ty, value)
+        .or_else(|err| match err {
+            JNIError::FieldNotFound { .. } => handle_not_found(env),
+            JNIError::JavaException =>
+                if let Some(FieldNotFound) = try_catch(env) {
+                    handle_not_found(env)
+                } else {
+                    panic_exception(env.exception_occurred().unwrap(), env)
+                },
+            err => panic!("Error occurred while accessing field: {err}")
+        })
+}
+
+/// Call a *setter method* of a Java Object with **value**, given the **method_name** and the **ty**pe of its only parameter.
+/// 
+/// Returns [`FromObjectError::FieldNotFound`] if the method does not exist.
+pub fn call_setter(
+    object: &JObject,
+    method_name: &str,
+    ty: &str,
+    value: JValue,
+    env: &mut JNIEnv,
+) -> Result<(), FromObjectError> {
+    #[derive(FromException)]
+    #[class(java.lang.NoSuchMethodError)]
+    struct MethodNotFound;
+
+    let error = |env: &mut JNIEnv| FromObjectError::FieldNotFound {
+        name: format!("{method_name}()"),
+        ty: ty.to_string(),
+        target_class: get_object_class_name(object, env)
+    };
+    env.call_method(object, method_name, format!("({ty})V"), &[value])
+        .map(|_| ())
+        .map_err(|err| match err {
+            JNIError::MethodNotFound { .. } => error(env),
+            JNIError::JavaException
+                => if let Some(MethodNotFound) = try_catch(env) {
+                    error(env)
+                } else {
+                    panic_exception(env.exception_occurred().unwrap(), env)
+                },
+            err => panic!("Error occurred while calling setter method: {err}")
+        })
+}
+
 /// Checks that a [`JObject`] is an **Array** (class name starts with `'['`),
 /// and returns the **length** of the Array.
 /// 
@@ -153,6 +240,137 @@ pub fn __obj_array_len(obj: &JObject, inner_ty: &str, env: &mut JNIEnv) -> usize
         as usize
 }
 
+/// Create a Java **Array** of Objects of class **elem_class** from a Rust *slice*,
+/// where the element `T` is converted to an Object with [`ToObject`].
+/// 
+/// This function is used by [ez_jni_macros::jni_fn].
+#[doc(hidden)]
+pub fn __create_object_array<'local, T>(slice: &[T], elem_class: &str, env: &mut JNIEnv<'local>) -> JObject<'local>
+where T: ToObject<'local> {
+    let array = env.new_object_array(slice.len() as jsize, elem_class, JObject::null())
+        .unwrap_or_else(|err| panic!("Failed to create Java Object \"{elem_class}\" array: {err}"));
+
+    for (i, element) in slice.iter().enumerate() {
+        let element = element.to_object(env);
+        env.set_object_array_element(&array, i as jsize, element)
+            .unwrap_or_else(|err| panic!("Failed to set the value of Object array at index {i}: {err}"));
+    }
+
+    array.into()
+}
+
+/// Checks that **object** is an instance of **class** (or `null`).
+/// 
+/// This function is used by [ez_jni_macros::call!] when the object is *cast* (e.g. `(object as java.util.List)`).
+/// Will `panic!` with a message naming both Classes if the Object is not an instance of **class**.
+#[doc(hidden)]
+pub fn __check_cast<'other_local>(object: impl AsRef<JObject<'other_local>>, class: &str, env: &mut JNIEnv) {
+    let object = object.as_ref();
+    let is_instance = env.is_instance_of(object, class)
+        .unwrap_or_else(|err| panic!("Failed to check if Object is an instance of {}: {err}", java_path_to_dot_notation(class)));
+
+    if !is_instance {
+        panic!("{}", FromObjectError::ClassMismatch {
+            obj_class: get_object_class_name(object, env),
+            target_class: Some(java_path_to_dot_notation(class)),
+        });
+    }
+}
+
+/// Gets the *signature* of a Rust type that is used in place of a Java type in [ez_jni_macros::call!] or [ez_jni_macros::new!].
+/// 
+/// The values of these types are always converted to and from *Objects*,
+/// so this will `panic!` if the type's [`JavaClass`] signature is of a *primitive* (e.g. for `type Id = i32;`),
+/// instead of letting JNI call a method with values of the wrong type.
+/// 
+/// This function is used by [ez_jni_macros::call!] and [ez_jni_macros::new!].
+#[doc(hidden)]
+pub fn __object_signature<T>() -> Cow<'static, str>
+where T: JavaClass + ?Sized {
+    let signature = T::signature();
+    if !(signature.starts_with('L') || signature.starts_with('[')) {
+        panic!(
+            "The signature of {} is \"{signature}\", but Rust types can only be used in place of Classes or Arrays. Use a Java primitive type instead.",
+            std::any::type_name::<T>()
+        );
+    }
+    signature
+}
+
+/// Converts a Rust type to and from the Java *primitive* that [`call!`] uses in place of its Class.
+/// 
+/// This is implemented by [`Checked`][crate::Checked] and [`Widened`][crate::Widened] (except `Widened<u64>`),
+/// so that they can be used with methods that take or return a primitive.
+/// 
+/// This trait is used by [ez_jni_macros::call!] and [ez_jni_macros::new!].
+#[doc(hidden)]
+pub trait __Primitive: Sized {
+    /// The Rust type of the Java primitive (e.g. [`i32`] for `int`).
+    type Primitive;
+    fn __from_primitive(value: Self::Primitive) -> Result<Self, FromObjectError>;
+    fn __to_primitive(&self) -> Self::Primitive;
+}
+
+/// Calls the implementation of a method in a specific **class** (a.k.a. a *non-virtual call*) on an **object**,
+/// even if the object's Class overrides the method.
+/// This is the equivalent of calling `super.method()` in Java.
+/// 
+/// `JNIEnv` does not have a wrapper for the `CallNonvirtual<Type>MethodA` functions, so this does the same as
+/// [`JNIEnv::call_method_unchecked()`], but with the non-virtual functions.
+/// 
+/// This function is used by [ez_jni_macros::call!].
+/// 
+/// # Safety
+/// 
+/// The **method_id** must belong to **class**, and the **ret**urn type and **args** must match the method's signature.
+#[doc(hidden)]
+pub unsafe fn __call_nonvirtual_method_unchecked<'local, 'other_local>(
+    env: &mut JNIEnv<'local>,
+    object: impl AsRef<JObject<'other_local>>,
+    class: &JClass,
+    method_id: JMethodID,
+    ret: ReturnType,
+    args: &[jvalue],
+) -> Result<JValueOwned<'local>, JNIError> {
+    let object = object.as_ref();
+    if object.is_null() {
+        return Err(JNIError::NullPtr("call_nonvirtual_method obj argument"));
+    }
+
+    let raw_env = env.get_raw();
+    let (object, class, method_id, args) = (object.as_raw(), class.as_raw(), method_id.into_raw(), args.as_ptr());
+    macro_rules! call_nonvirtual {
+        ($function:ident) => {
+            ((**raw_env).$function.ok_or(JNIError::JNIEnvMethodNotFound(stringify!($function)))?)(raw_env, object, class, method_id, args)
+        };
+    }
+
+    let value = match ret {
+        ReturnType::Object | ReturnType::Array => JValueOwned::Object(JObject::from_raw(call_nonvirtual!(CallNonvirtualObjectMethodA))),
+        ReturnType::Primitive(primitive) => match primitive {
+            Primitive::Boolean => JValueOwned::Bool(call_nonvirtual!(CallNonvirtualBooleanMethodA)),
+            Primitive::Char => JValueOwned::Char(call_nonvirtual!(CallNonvirtualCharMethodA)),
+            Primitive::Byte => JValueOwned::Byte(call_nonvirtual!(CallNonvirtualByteMethodA)),
+            Primitive::Short => JValueOwned::Short(call_nonvirtual!(CallNonvirtualShortMethodA)),
+            Primitive::Int => JValueOwned::Int(call_nonvirtual!(CallNonvirtualIntMethodA)),
+            Primitive::Long => JValueOwned::Long(call_nonvirtual!(CallNonvirtualLongMethodA)),
+            Primitive::Float => JValueOwned::Float(call_nonvirtual!(CallNonvirtualFloatMethodA)),
+            Primitive::Double => JValueOwned::Double(call_nonvirtual!(CallNonvirtualDoubleMethodA)),
+            Primitive::Void => {
+                call_nonvirtual!(CallNonvirtualVoidMethodA);
+                JValueOwned::Void
+            },
+        },
+    };
+
+    // Same as what JNIEnv does after a call
+    if env.exception_check()? {
+        return Err(JNIError::JavaException);
+    }
+
+    Ok(value)
+}
+
 /// Create a Java **Array** from a Rust [slice](https://doc.rust-lang.org/std/primitive.slice.html),
 /// where the element `T` is a *primitive*.
 /// 
@@ -190,7 +408,8 @@ pub(crate) fn create_java_prim_array<'local, 'a, T>(
 
 /// Get a Rust [`Vec`] from a Java **Array**, where the element `T` is a *primitive*.
 /// 
-/// This function checks that the Object is of the correct [`Type`][crate::FromObject::PATH].
+/// This function checks that the Object is a Java Array of **array_class** (e.g. `[I`),
+/// because reading an Array of another type would crash the VM.
 /// 
 /// **obj** is the Java Array.
 /// 
@@ -200,23 +419,27 @@ pub(crate) fn create_java_prim_array<'local, 'a, T>(
 /// ## Example
 /// 
 /// ```ignore
-/// ez_jni::utils::get_java_prim_array(object, JNIEnv::get_byte_array_region, env);
+/// ez_jni::utils::get_java_prim_array(object, "[B", JNIEnv::get_byte_array_region, env);
 /// ```
 pub(crate) fn get_java_prim_array<'local, 'other, 'a, T>(
     obj: &'a JObject<'other>,
+    array_class: &str,
     filler: fn(&JNIEnv<'local>, &'a JPrimitiveArray<'other, T>, jsize, &mut [T]) -> jni::errors::Result<()>,
     env: &mut JNIEnv<'local>
-) -> Vec<T>
+) -> Result<Vec<T>, FromObjectError>
     where T: jni::objects::TypeArray + crate::FromObject<'local>
 {
-    let array = <&'a JPrimitiveArray<'other, T>>::from(obj);
+    if obj.is_null() {
+        return Err(FromObjectError::Null);
+    }
     // Check object's type
-    // let class = env.get_object_class(obj)
-    //     .unwrap_or_else(|err| panic!("Failed to get Object's class: {err}"));
-    // let ty = call!(class.getName() -> String);
-    // if ty != T::PATH {
-    //     panic!("Expected object's type to be \"{}\", but is actually \"{ty}\"", T::PATH)
-    // }
+    if !env.is_instance_of(obj, array_class).unwrap_or(false) {
+        return Err(FromObjectError::ClassMismatch {
+            obj_class: get_object_class_name(obj, env),
+            target_class: Some(java_path_to_dot_notation(array_class))
+        });
+    }
+    let array = <&'a JPrimitiveArray<'other, T>>::from(obj);
 
     let len = env.get_array_length(array)
         .unwrap_or_else(|err| panic!("Failed to check Array's length: {err}"))
@@ -228,7 +451,7 @@ pub(crate) fn get_java_prim_array<'local, 'other, 'a, T>(
     filler(env, array, 0, &mut vec)
         .unwrap_or_else(|err| panic!("Failed to read Array elements: {err}"));
 
-    vec
+    Ok(vec)
 }
 
 /// Convert the first letter of a String into uppercase
@@ -7,6 +7,9 @@ public final class Test {
     Test(int member) {
         this.memberField = member;
     }
+    Test(Test other) {
+        this.memberField = other.memberField;
+    }
     Test(String len) throws NullPointerException {
         if (len == null) {
             throw new NullPointerException("String was null");
@@ -22,6 +25,14 @@ public final class Test {
     public int memberGetter() {
         return this.memberField;
     }
+    // A property without a field of the same name
+    private String label = "";
+    public String getText() { return this.label; }
+    public void setText(String text) { this.label = text; }
+
+    public static final int CONSTANT = 3;
+    public static String staticString = "Hello, World!";
+    public static int[] staticArray = null;
 
     public static void    getVoid()    { return; }
     public static boolean getBoolean() { return true; }
@@ -60,6 +71,23 @@ public final class Test {
     public static void objArgs(Object l, String s) { }
     public static void primArrayArgs(boolean[] z, char[] c, byte[] b, short[] s, int[] i, long[] j, float[] f, double[] d) { }
     public static void objArrayArgs(Object[] l, String[] s) { }
+    public static int[][] getIntMatrix() { return new int[][] {{1, 2}, {3}}; }
+    public static String[][] getNullStringMatrix() { return new String[][] {{"Hello", "World"}, null}; }
+    public static String matrixArgs(int[][] i, String[][] s) { return java.util.Arrays.deepToString(i) + java.util.Arrays.deepToString(s); }
+    public static int sumVarargs(int... values) {
+        int sum = 0;
+        for (int value : values) sum += value;
+        return sum;
+    }
+    public static String joinVarargs(String separator, String... strings) { return String.join(separator, strings); }
+    public static Test getTest(int member) { return new Test(member); }
+    public static Test nullTest() { return null; }
+    public static Test[] getTestArray() { return new Test[] {new Test(1), new Test(2)}; }
+    public static int getMember(Test test) { return test.memberField; }
+    public static String overloaded(String s) { return "String"; }
+    public static String overloaded(Test test) { return "Test"; }
+    public String memberOverloaded(String s) { return "String"; }
+    public String memberOverloaded(Test test) { return "Test"; }
 
     public static class Instanced {
         Instanced() {}
@@ -69,6 +97,11 @@ public final class Test {
         public void args(boolean z, Object l) { }
         public void arrayArgs(boolean[] z, Object[] l) { }
     }
+    public static class InstancedChild extends Instanced {
+        InstancedChild() {}
+        @Override
+        public boolean getBoolean() { return false; }
+    }
 
     public static sealed class SumClass {
         public static final class SumClass1 extends SumClass {
@@ -86,12 +119,72 @@ public final class Test {
         }
     }
 
+    public static final class RustPanic extends RuntimeException {
+        public RustPanic(String message) { super(message); }
+        public String panicMessage;
+        public String file;
+        public int line;
+        public int column;
+    }
+
     public void testNativeMethods() {
         this.test_jni_fn_1();
         this.test_jni_fn_2("Hello, World!");
         this.test_jni_fn_3("Hello, World!");
+        String[] strings = this.test_jni_fn_4(new int[] { 1, 2, 3 });
+        if (!java.util.Arrays.equals(strings, new String[] { "1", "2", "3" })) {
+            throw new AssertionError("test_jni_fn_4 returned the wrong array");
+        }
+        if (this.test_jni_fn_5(null, new Test(5)).memberField != 5
+         || this.test_jni_fn_5("abc", new Test(5)).memberField != 8) {
+            throw new AssertionError("test_jni_fn_5 returned the wrong object");
+        }
+        if (this.test_jni_fn_6('a', true) != 'A' || this.test_jni_fn_6('a', false) != 'a') {
+            throw new AssertionError("test_jni_fn_6 returned the wrong char");
+        }
+        if (this.test_jni_fn_7(3) != 3) {
+            throw new AssertionError("test_jni_fn_7 returned the wrong value");
+        }
+        try {
+            this.test_jni_fn_7(-1);
+            throw new AssertionError("test_jni_fn_7 did not throw");
+        } catch (IllegalArgumentException e) {
+            if (!e.getMessage().equals("-1 is negative")) {
+                throw new AssertionError("test_jni_fn_7 threw the wrong message");
+            }
+        }
+        try {
+            this.test_jni_fn_8();
+            throw new AssertionError("test_jni_fn_8 did not throw");
+        } catch (RustPanic e) {
+            if (!e.panicMessage.equals("test panic") || !e.file.endsWith("jni_fn.rs") || e.line == 0 || e.column == 0) {
+                throw new AssertionError("test_jni_fn_8 threw a RustPanic with the wrong data");
+            }
+        }
+        try {
+            this.test_jni_fn_9();
+            throw new AssertionError("test_jni_fn_9 did not throw");
+        } catch (Exception e) {
+            Throwable[] suppressed = e.getSuppressed();
+            if (!e.getMessage().equals("exception") || suppressed.length != 1
+             || !suppressed[0].getMessage().startsWith("Rust did not catch the Exception thrown by me.test.Test.throwPrim() at ")
+             || !suppressed[0].getMessage().contains("jni_fn.rs")) {
+                throw new AssertionError("test_jni_fn_9 threw an Exception without the Rust context");
+            }
+        }
+        String[][] matrix = this.test_jni_fn_10(new int[][] { { 1, 2 }, { 3 } });
+        if (!java.util.Arrays.deepEquals(matrix, new String[][] { { "1", "2" }, { "3" } })) {
+            throw new AssertionError("test_jni_fn_10 returned the wrong array");
+        }
     }
     private native void test_jni_fn_1();
     private native int test_jni_fn_2(String s);
     private native String test_jni_fn_3(String s);
+    private native String[] test_jni_fn_4(int[] array);
+    private native Test test_jni_fn_5(String s, Test other);
+    private native char test_jni_fn_6(char c, boolean upper);
+    private native int test_jni_fn_7(int i);
+    private native void test_jni_fn_8();
+    private native void test_jni_fn_9();
+    private native String[][] test_jni_fn_10(int[][] matrix);
 }
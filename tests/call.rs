@@ -2,8 +2,8 @@ mod common;
 
 use std::panic::{catch_unwind, AssertUnwindSafe};
 
-use jni::objects::JObject;
-use ez_jni::{call, new};
+use jni::{objects::JObject, JNIEnv};
+use ez_jni::{call, new, FromObject, JavaClass, ToObject};
 
 #[test]
 fn return_primitives() {
@@ -230,6 +230,95 @@ fn arguments() {
     call!(static me.test.Test.objArrayArgs([java.lang.Object]([null, null]), [java.lang.String](s)) -> void);
 }
 
+#[test]
+fn multi_dimensional_arrays() {
+    setup_env!(env);
+
+    let matrix = call!(static me.test.Test.getIntMatrix() -> [[int]]);
+    assert_eq!(matrix.len(), 2);
+    assert_eq!(*matrix[0], [1, 2]);
+    assert_eq!(*matrix[1], [3]);
+    let matrix = call!(static me.test.Test.getNullStringMatrix() -> [Option<[String]>]);
+    assert_eq!(matrix[0].as_deref(), Some(["Hello".to_string(), "World".to_string()].as_slice()));
+    assert_eq!(matrix[1], None);
+    // Should panic because the Array contains null
+    catch_unwind(AssertUnwindSafe(|| call!(static me.test.Test.getNullStringMatrix() -> [[String]])))
+        .unwrap_err();
+
+    assert_eq!(
+        call!(static me.test.Test.matrixArgs([[int]]([[1, 2], [3, 4]]), [[String]](vec![vec!["a"], vec![]])) -> String),
+        "[[1, 2], [3, 4]][[a], []]"
+    );
+}
+
+#[test]
+fn varargs() {
+    setup_env!(env);
+
+    assert_eq!(call!(static me.test.Test.sumVarargs(int...(1, 2, 3)) -> int), 6);
+    assert_eq!(call!(static me.test.Test.sumVarargs(int...()) -> int), 0);
+    assert_eq!(call!(static me.test.Test.joinVarargs(String(", "), String...("a", String::from("b"), null)) -> String), "a, b, null");
+    assert_eq!(call!(static java.lang.String.format(String("%s %s %s"), java.lang.Object...(3, "abc", null)) -> String), "3 abc null");
+    let list = call!(static java.util.Arrays.asList(java.lang.Object...(new!(java.lang.Object()), true)) -> java.util.List);
+    assert_eq!(call!(list.size() -> int), 2);
+}
+
+#[derive(Debug, PartialEq, FromObject, ToObject, JavaClass)]
+#[class(me.test.Test)]
+struct MyTest {
+    member_field: i32,
+}
+
+#[test]
+fn rust_types() {
+    setup_env!(env);
+
+    assert_eq!(call!(static me.test.Test.getTest(int(5)) -> MyTest), MyTest { member_field: 5 });
+    assert_eq!(call!(static me.test.Test.getTest(int(5)) -> crate::MyTest), MyTest { member_field: 5 });
+    assert_eq!(call!(static me.test.Test.getTest(int(5)) -> Option<MyTest>), Some(MyTest { member_field: 5 }));
+    assert_eq!(call!(static me.test.Test.nullTest() -> Option<MyTest>), None);
+    assert_eq!(call!(static me.test.Test.getTestArray() -> Vec<MyTest>), [MyTest { member_field: 1 }, MyTest { member_field: 2 }]);
+    assert_eq!(call!(static me.test.Test.getIntArray() -> Box<[i32]>).as_ref(), [1, 2, 3]);
+    // Should panic if the Object is null and the type is not Option
+    catch_unwind(AssertUnwindSafe(|| call!(static me.test.Test.nullTest() -> MyTest)))
+        .unwrap_err();
+
+    let test = MyTest { member_field: 7 };
+    assert_eq!(call!(static me.test.Test.getMember(MyTest(test)) -> int), 7);
+    assert_eq!(call!(static me.test.Test.sumVarargs(Vec<i32>(vec![1, 2, 3])) -> int), 6);
+
+    let obj = new!(me.test.Test(MyTest(MyTest { member_field: 4 })));
+    assert_eq!(call!(obj.memberGetter() -> int), 4);
+    // The Java method throws because the argument is null
+    catch_unwind(AssertUnwindSafe(|| call!(static me.test.Test.getMember(MyTest(null)) -> int)))
+        .unwrap_err();
+
+    // Rust types with the signature of a primitive can't be used, even if the method exists
+    type Id = i32;
+    let err = catch_unwind(AssertUnwindSafe(|| call!(static me.test.Test.getInt() -> Id)))
+        .unwrap_err();
+    assert!(err.downcast_ref::<String>().unwrap().contains("Rust types can only be used in place of Classes or Arrays"));
+    catch_unwind(AssertUnwindSafe(|| call!(static me.test.Test.getMember(core::primitive::i32(3)) -> int)))
+        .unwrap_err();
+}
+
+#[test]
+fn generic_rust_types() {
+    setup_env!(env);
+
+    // Each instantiation of a generic function calls the overload for its own signature
+    fn overloaded<'local, T: ToObject<'local> + JavaClass>(value: T, obj: &JObject, env: &mut JNIEnv<'local>) -> (String, String, i32) {
+        let static_result = call!(static me.test.Test.overloaded(T(&value)) -> String);
+        let member_result = call!(obj.memberOverloaded(T(&value)) -> String);
+        let new_obj = new!(me.test.Test(T(&value)));
+        (static_result, member_result, call!(new_obj.memberGetter() -> int))
+    }
+    let obj = new!(me.test.Test());
+    assert_eq!(overloaded("abcd".to_string(), &obj, &mut env), ("String".to_string(), "String".to_string(), 4));
+    assert_eq!(overloaded(MyTest { member_field: 7 }, &obj, &mut env), ("Test".to_string(), "Test".to_string(), 7));
+    assert_eq!(overloaded("ab".to_string(), &obj, &mut env), ("String".to_string(), "String".to_string(), 2));
+}
+
 #[test]
 fn constructor() {
     setup_env!(env);
@@ -263,4 +352,48 @@ fn obj_method() {
     // Test object expression
     call!((obj).getBoolean() -> boolean);
     call!({ obj }.getBoolean() -> boolean);
-}
\ No newline at end of file
+}
+
+#[test]
+fn cast_object() {
+    setup_env!(env);
+
+    let list = new!(java.util.ArrayList());
+    assert_eq!(call!((list as java.util.List).size() -> int), 0);
+    assert_eq!(call!((&list as java.util.Collection).size() -> int), 0);
+    // Should panic if the Object is not an instance of the Class
+    let obj = new!(java.lang.Object());
+    catch_unwind(AssertUnwindSafe(|| call!((obj as java.util.List).size() -> int)))
+        .unwrap_err();
+}
+#[test]
+fn nonvirtual_method() {
+    setup_env!(env);
+
+    let obj = new!(me.test.Test$InstancedChild());
+    assert!(!call!(obj.getBoolean() -> bool));
+    assert!(call!(super(me.test.Test$Instanced) obj.getBoolean() -> bool));
+    assert_eq!(call!(super(me.test.Test$Instanced) (obj).getBoolean() -> Result<bool, String>), Ok(true));
+    assert!(call!(super(java.lang.Object) obj.toString() -> String).starts_with("me.test.Test$InstancedChild@"));
+}
+
+#[test]
+fn cached_ids() {
+    setup_env!(env);
+    fn to_string(obj: &JObject, env: &mut JNIEnv) -> String {
+        call!(obj.toString() -> String)
+    }
+
+    // The same call site is used with Objects of different Classes, so the cached Method ID must be looked up again
+    let objects = [new!(java.lang.Integer(int(3))), env.new_string("Hello").unwrap().into(), new!(java.lang.Integer(int(5)))];
+    for _ in 0..2 {
+        let strings = objects.iter()
+            .map(|obj| to_string(obj, &mut env))
+            .collect::<Vec<_>>();
+        assert_eq!(strings, ["3", "Hello", "5"]);
+    }
+
+    // Calling a method on null must not use the cached ID
+    catch_unwind(AssertUnwindSafe(|| to_string(&JObject::null(), &mut env)))
+        .unwrap_err();
+}
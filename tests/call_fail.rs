@@ -72,6 +72,17 @@ error: Option can't be used with primitives, only Classes.
 5 | call!(static me.test.Test.getIntArray() -> [Option<int>]);
   |                                             ^^^^^^
 "));
+
+assert_compile_fail(t, "option_unsigned", "
+use ez_jni::call;
+call!(static me.test.Test.getInt() -> Option<Checked<u32>>);
+    ", Some("\
+error: Option can't be used with primitives, only Classes. In call!, Checked<u32> is converted from the primitive 'int'.
+ --> ./target/tmp/compile_fail/option_unsigned.rs:5:46
+  |
+5 | call!(static me.test.Test.getInt() -> Option<Checked<u32>>);
+  |                                              ^^^^^^^
+"));
 }
 
 #[test]
@@ -132,4 +143,15 @@ error: Can't use 'null' as value of primitive parameter type.
 5 | call!(static me.test.Test.method([int]([1, null])) -> void);
   |                                            ^^^^
 "));
+
+assert_compile_fail(t, "param_unsigned_null", "
+use ez_jni::call;
+call!(static me.test.Test.method(Checked<u32>(null)) -> void);
+    ", Some("\
+error: Can't use 'null' as value of primitive parameter type.
+ --> ./target/tmp/compile_fail/param_unsigned_null.rs:5:47
+  |
+5 | call!(static me.test.Test.method(Checked<u32>(null)) -> void);
+  |                                               ^^^^
+"));
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+mod common;
+
+use ez_jni::{field, new};
+
+#[test]
+fn field() {
+    setup_env!(env);
+    let object = new!(me.test.Test(int(3)));
+
+    assert_eq!(field!(object.memberField -> int), 3);
+    field!(object.memberField = int(5));
+    assert_eq!(field!(object.memberField -> int), 5);
+    assert_eq!(field!((object as me.test.Test).memberField -> int), 5);
+
+    // Falls back to getter and setter
+    assert_eq!(field!(object.text -> String), "");
+    field!(object.text = String("Hello, World!"));
+    assert_eq!(field!(object.text -> String), "Hello, World!");
+    field!((object).text = String(null));
+    assert_eq!(field!(object.text -> Option<String>), None);
+}
+
+#[test]
+fn static_field() {
+    setup_env!(env);
+
+    assert_eq!(field!(static me.test.Test.CONSTANT -> int), 3);
+    assert_eq!(field!(static java.lang.Integer.MAX_VALUE -> int), i32::MAX);
+    field!(static java.lang.System.out -> java.io.PrintStream);
+
+    assert_eq!(field!(static me.test.Test.staticString -> String), "Hello, World!");
+    field!(static me.test.Test.staticString = String("Goodbye"));
+    assert_eq!(field!(static me.test.Test.staticString -> Option<String>).as_deref(), Some("Goodbye"));
+
+    assert_eq!(field!(static me.test.Test.staticArray -> Option<[int]>), None);
+    field!(static me.test.Test.staticArray = [int]([1, 2, 3]));
+    assert_eq!(field!(static me.test.Test.staticArray -> [int]).as_ref(), [1, 2, 3]);
+}
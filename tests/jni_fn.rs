@@ -1,20 +1,131 @@
 mod common;
 
-use ez_jni::{jni_fn, FromObject, ToObject};
+use ez_jni::{jni_fn, call, new, FromObject, ToObject, ToException};
+use jni::{objects::JThrowable, JNIEnv, NativeMethod};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(FromObject, ToObject)]
+#[class(me.test.Test)]
+struct TestObject {
+    member_field: i32,
+}
+
+struct NegativeError(i32);
+impl<'local> ToException<'local> for NegativeError {
+    fn to_exception(&self, env: &mut JNIEnv<'local>) -> JThrowable<'local> {
+        new!(java.lang.IllegalArgumentException(String(format!("{} is negative", self.0)))).into()
+    }
+}
 
 jni_fn! {
     #[class(me.test.Test)]
-    pub fn test_jni_fn_1<'local>() { }
+    pub fn test_jni_fn_1<'local>(self) {
+        // Instance methods receive the Object
+        call!(this.memberGetter() -> int);
+    }
 
     #[class = "me.test.Test"]
-    pub fn test_jni_fn_2<'local>(s: java.lang.String) -> int {
-        String::from_object(&s, env).unwrap().len() as i32
+    pub fn test_jni_fn_2<'local>(self, s: String) -> int {
+        s.len() as i32
     }
 
     #[class = "me.test.Test"]
-    pub fn test_jni_fn_3<'local>(s: java.lang.String) -> java.lang.String {
-        String::from_object(&s, env).unwrap().to_object(env).into_raw()
+    pub fn test_jni_fn_3<'local>(self, s: java.lang.String) -> java.lang.String {
+        s
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_4<'local>(self, array: [int]) -> [String] {
+        array.iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
     }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_5<'local>(self, s: Option<String>, #[class(me.test.Test)] other: TestObject) -> me.test.Test {
+        let len = match s {
+            Some(s) => s.len() as i32,
+            None => return other,
+        };
+        TestObject { member_field: other.member_field + len }
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_6<'local>(self, c: char, upper: bool) -> char {
+        if upper {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_7<'local>(self, i: int) -> Result<int, NegativeError> {
+        if i < 0 {
+            Err(NegativeError(i))?
+        }
+        Ok(i)
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_8<'local>(self) {
+        panic!("test panic")
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_9<'local>(self) {
+        // The Exception is rethrown to Java
+        call!(static me.test.Test.throwPrim() -> bool);
+    }
+
+    #[class(me.test.Test)]
+    pub fn test_jni_fn_10<'local>(self, matrix: [[int]]) -> [[String]] {
+        matrix.iter()
+            .map(|row| row.iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+            )
+            .collect::<Vec<_>>()
+    }
+}
+
+#[test]
+fn native_methods() {
+    setup_env!(env);
+    // Link the exported functions to the Java Class because the test binary is not loaded as a library
+    let class = env.find_class("me/test/Test").unwrap();
+    env.register_native_methods(&class, &[
+        NativeMethod { name: "test_jni_fn_1".into(), sig: "()V".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_11 as *mut _ },
+        NativeMethod { name: "test_jni_fn_2".into(), sig: "(Ljava/lang/String;)I".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_12 as *mut _ },
+        NativeMethod { name: "test_jni_fn_3".into(), sig: "(Ljava/lang/String;)Ljava/lang/String;".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_13 as *mut _ },
+        NativeMethod { name: "test_jni_fn_4".into(), sig: "([I)[Ljava/lang/String;".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_14 as *mut _ },
+        NativeMethod { name: "test_jni_fn_5".into(), sig: "(Ljava/lang/String;Lme/test/Test;)Lme/test/Test;".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_15 as *mut _ },
+        NativeMethod { name: "test_jni_fn_6".into(), sig: "(CZ)C".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_16 as *mut _ },
+        NativeMethod { name: "test_jni_fn_7".into(), sig: "(I)I".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_17 as *mut _ },
+        NativeMethod { name: "test_jni_fn_8".into(), sig: "()V".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_18 as *mut _ },
+        NativeMethod { name: "test_jni_fn_9".into(), sig: "()V".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_19 as *mut _ },
+        NativeMethod { name: "test_jni_fn_10".into(), sig: "([[I)[[Ljava/lang/String;".into(), fn_ptr: Java_me_test_Test_test_1jni_1fn_110 as *mut _ },
+    ]).unwrap();
+    ez_jni::set_panic_exception_class("me.test.Test$RustPanic");
+
+    // A hook set before calling any jni_fn must still be called when a jni_fn panics
+    static HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+    std::panic::set_hook(Box::new(|_| { HOOK_CALLS.fetch_add(1, Ordering::Relaxed); }));
+
+    let object = ez_jni::new!(me.test.Test());
+    call!(object.testNativeMethods() -> void);
+
+    let _ = std::panic::take_hook();
+    assert_eq!(HOOK_CALLS.load(Ordering::Relaxed), 2);
 }
 
-// Can't test runtime because unsure how to link exported functions to Java
\ No newline at end of file
+#[test]
+fn throw_null_exception() {
+    setup_env!(env);
+    // A null Exception is thrown as a NullPointerException
+    let result = ez_jni::__throw::catch_throw_result(&mut env, |_| Err::<i32, _>(None::<String>));
+    assert_eq!(result, 0);
+    let exception = env.exception_occurred().unwrap();
+    env.exception_clear().unwrap();
+    assert!(env.is_instance_of(&exception, "java/lang/NullPointerException").unwrap());
+}
@@ -1,6 +1,8 @@
 mod common;
 
-use ez_jni::{call, new, FromException, FromObject, ToObject};
+use std::{collections::{BTreeMap, HashMap, HashSet}, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use ez_jni::{call, new, BigDecimal, Checked, FromException, FromObject, FromObjectError, JavaClass, List, NioPath, Optional, OptionalDouble, OptionalInt, OptionalLong, ToException, ToObject, Widened};
 use jni::objects::JObject;
 
 /// Tests the implementations of FromObject, etc. for *standard library* types.
@@ -134,6 +136,28 @@ fn implementations() {
         ['a', 'b', 'c'],
         Box::<[char]>::from_object(&obj, &mut env).unwrap().as_ref()
     );
+    // Primitive Arrays must have the same element type
+    obj = [1i64, 2].to_object(&mut env);
+    assert!(matches!(Vec::<i32>::from_object(&obj, &mut env), Err(FromObjectError::ClassMismatch { .. })));
+    obj = [Some(1i32), None].to_object(&mut env);
+    assert!(matches!(Vec::<i32>::from_object(&obj, &mut env), Err(FromObjectError::ClassMismatch { .. })));
+    // Multi-dimensional Arrays
+    obj = [vec![1i32, 2], vec![3]].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[[I").unwrap());
+    assert_eq!(
+        [vec![1, 2], vec![3]],
+        Vec::<Vec<i32>>::from_object(&obj, &mut env).unwrap().as_slice()
+    );
+    obj = [Some(vec!["Hello"]), None].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[[Ljava/lang/String;").unwrap());
+    assert_eq!(
+        [Some(vec!["Hello".to_string()].into_boxed_slice()), None],
+        Vec::<Option<Box<[String]>>>::from_object(&obj, &mut env).unwrap().as_slice()
+    );
+    Vec::<Vec<String>>::from_object(&obj, &mut env).unwrap_err();
+    // Empty Arrays still have the Class of the elements
+    obj = Vec::<Vec<f64>>::new().to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[[D").unwrap());
 }
 
 #[derive(FromObject)]
@@ -163,6 +187,17 @@ struct MyClass3 {
     member: i32,
 }
 
+#[derive(FromObject, ToObject)]
+#[class(me.test.Test)]
+#[constructor]
+struct MyClass4(#[field(name = memberField)] i32);
+
+#[derive(FromObject, ToObject, JavaClass)]
+#[class(me.test.Test)]
+struct MyClass5 {
+    member_field: i32,
+}
+
 #[derive(Debug, FromObject, PartialEq, Eq)]
 #[class(me.test.Test$SumClass)]
 enum MyEnumClass {
@@ -172,6 +207,17 @@ enum MyEnumClass {
     Variant2 { str: String },
 }
 
+#[derive(Debug, FromObject, ToObject, JavaClass, PartialEq, Eq)]
+#[class(me.test.Test$SumClass)]
+enum MyEnumClass2 {
+    #[class(me.test.Test$SumClass$SumClass1)]
+    #[constructor]
+    Variant1 { number: i32 },
+    #[class(me.test.Test$SumClass$SumClass2)]
+    #[constructor]
+    Variant2 { str: String },
+}
+
 #[test]
 fn from_object() {
     setup_env!(env);
@@ -213,6 +259,359 @@ fn from_object() {
     );
 }
 
+#[test]
+fn to_object() {
+    setup_env!(env);
+    const VAL: i32 = 3;
+
+    let object = MyClass5 { member_field: VAL }.to_object(&mut env);
+    assert_eq!(call!(object.memberGetter() -> int), VAL);
+    assert_eq!(
+        MyClass5::from_object(&object, &mut env)
+            .unwrap()
+            .member_field,
+        VAL
+    );
+
+    let object = MyClass4(VAL).to_object(&mut env);
+    assert_eq!(call!(object.memberGetter() -> int), VAL);
+    assert_eq!(MyClass4::from_object(&object, &mut env).unwrap().0, VAL);
+
+    let variant = MyEnumClass2::Variant1 { number: VAL };
+    let object = variant.to_object(&mut env);
+    assert_eq!(MyEnumClass2::from_object(&object, &mut env).unwrap(), variant);
+
+    let variant = MyEnumClass2::Variant2 { str: "Hello, World!".to_string() };
+    let object = variant.to_object(&mut env);
+    assert_eq!(MyEnumClass2::from_object(&object, &mut env).unwrap(), variant);
+}
+
+#[test]
+fn object_arrays() {
+    setup_env!(env);
+
+    // Arrays of derived types use the class of the struct or enum
+    let mut obj = [MyClass5 { member_field: 1 }, MyClass5 { member_field: 2 }].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Lme/test/Test;").unwrap());
+    assert_eq!(
+        [1, 2],
+        Vec::<MyClass5>::from_object(&obj, &mut env).unwrap()
+            .iter()
+            .map(|element| element.member_field)
+            .collect::<Box<[_]>>()
+            .as_ref()
+    );
+    obj = vec![Some(MyClass4(3)), None].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Lme/test/Test;").unwrap());
+    assert_eq!(
+        [Some(3), None],
+        Box::<[Option<MyClass4>]>::from_object(&obj, &mut env).unwrap()
+            .iter()
+            .map(|element| element.as_ref().map(|element| element.0))
+            .collect::<Box<[_]>>()
+            .as_ref()
+    );
+    assert!(Vec::<MyClass4>::from_object(&obj, &mut env).is_err());
+
+    let variants = [MyEnumClass2::Variant1 { number: 3 }, MyEnumClass2::Variant2 { str: "Hello".to_string() }];
+    obj = variants.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Lme/test/Test$SumClass;").unwrap());
+    assert_eq!(variants, Vec::<MyEnumClass2>::from_object(&obj, &mut env).unwrap().as_slice());
+    // The elements must be of the class of the struct
+    assert!(Vec::<MyClass5>::from_object(&obj, &mut env).is_err());
+
+    // Arrays of boxed primitives and nullable Objects
+    obj = [Some(1i32), None].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Ljava/lang/Integer;").unwrap());
+    assert_eq!(
+        [Some(1), None],
+        Vec::<Option<i32>>::from_object(&obj, &mut env).unwrap().as_slice()
+    );
+    let objects = call!(static me.test.Test.getNullObjectArray() -> [Option<java.lang.Object>]);
+    obj = objects.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Ljava/lang/Object;").unwrap());
+    let objects = Vec::<Option<JObject>>::from_object(&obj, &mut env).unwrap();
+    assert!(objects[0].is_some());
+    assert!(objects[1].is_none());
+    assert!(Vec::<MyClass5>::from_object(&JObject::null(), &mut env).is_err());
+}
+
+#[test]
+fn collections() {
+    setup_env!(env);
+
+    // A Vec can be converted from any Collection
+    let mut obj = call!(static java.util.Arrays.asList(java.lang.Object...(1, 2, 3)) -> java.util.List);
+    assert_eq!(Vec::<i32>::from_object(&obj, &mut env).unwrap(), [1, 2, 3]);
+    obj = call!(static java.util.Arrays.asList(java.lang.Object...("a", null)) -> java.util.List);
+    assert_eq!(Vec::<Option<String>>::from_object(&obj, &mut env).unwrap(), [Some("a".to_string()), None]);
+    assert!(Vec::<String>::from_object(&obj, &mut env).is_err());
+
+    let list = List(vec![MyClass4(1), MyClass4(2)]);
+    obj = list.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/util/ArrayList").unwrap());
+    assert_eq!(call!(obj.size() -> int), 2);
+    assert_eq!(
+        List::<MyClass4>::from_object(&obj, &mut env).unwrap()
+            .iter()
+            .map(|element| element.0)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+
+    let set = HashSet::from(["a".to_string(), "b".to_string()]);
+    obj = set.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/util/HashSet").unwrap());
+    assert_eq!(HashSet::<String>::from_object(&obj, &mut env).unwrap(), set);
+
+    let map = HashMap::from([("a".to_string(), 1i32), ("b".to_string(), 2)]);
+    obj = map.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/util/HashMap").unwrap());
+    assert_eq!(HashMap::<String, i32>::from_object(&obj, &mut env).unwrap(), map);
+    // Entries keep the order of the keys
+    let map = BTreeMap::from([(3i64, "c"), (1, "a"), (2, "b")]);
+    obj = map.to_object(&mut env);
+    assert_eq!(call!(obj.toString() -> String), "{1=a, 2=b, 3=c}");
+    let map = BTreeMap::from([(2i64, vec![1i32, 2]), (1, vec![])]);
+    obj = map.to_object(&mut env);
+    assert_eq!(BTreeMap::<i64, Vec<i32>>::from_object(&obj, &mut env).unwrap(), map);
+    // Collections with more elements than the local reference table holds by default
+    let map = (0..1000i32).map(|i| (i, i.to_string())).collect::<HashMap<_, _>>();
+    obj = map.to_object(&mut env);
+    assert_eq!(HashMap::<i32, String>::from_object(&obj, &mut env).unwrap(), map);
+    let list = List((0..1000i32).collect());
+    obj = list.to_object(&mut env);
+    assert_eq!(List::<i32>::from_object(&obj, &mut env).unwrap(), list);
+
+    // Rust collections can be used directly in call!
+    let set = call!(static java.util.Collections.unmodifiableSet(HashSet<i32>(HashSet::from([1, 2]))) -> HashSet<i32>);
+    assert_eq!(set, HashSet::from([1, 2]));
+    let list = call!(static java.util.Collections.unmodifiableList(List<String>(List(vec!["a".to_string()]))) -> List<String>);
+    assert_eq!(*list, ["a"]);
+
+    // Objects that are not Collections or Maps
+    obj = new!(java.lang.Object());
+    assert!(HashSet::<i32>::from_object(&obj, &mut env).is_err());
+    assert!(HashMap::<i32, i32>::from_object(&obj, &mut env).is_err());
+    assert!(List::<i32>::from_object(&JObject::null(), &mut env).is_err());
+}
+
+#[test]
+fn optionals() {
+    setup_env!(env);
+
+    let mut obj = Optional(Some("Hello".to_string())).to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/util/Optional").unwrap());
+    assert_eq!(call!(obj.toString() -> String), "Optional[Hello]");
+    assert_eq!(Optional::<String>::from_object(&obj, &mut env).unwrap(), Optional(Some("Hello".to_string())));
+    obj = Optional::<MyClass4>(None).to_object(&mut env);
+    assert!(!call!(obj.isPresent() -> bool));
+    assert!(Optional::<MyClass4>::from_object(&obj, &mut env).unwrap().is_none());
+    // A value that is converted to null is empty
+    obj = Optional(Some(None::<i32>)).to_object(&mut env);
+    assert_eq!(*Optional::<Option<i32>>::from_object(&obj, &mut env).unwrap(), None);
+
+    obj = OptionalInt(Some(3)).to_object(&mut env);
+    assert_eq!(call!(obj.getAsInt() -> int), 3);
+    assert_eq!(OptionalInt::from_object(&obj, &mut env).unwrap(), OptionalInt(Some(3)));
+    obj = OptionalLong(Some(-3)).to_object(&mut env);
+    assert_eq!(call!(obj.getAsLong() -> long), -3);
+    assert_eq!(OptionalLong::from_object(&obj, &mut env).unwrap(), OptionalLong(Some(-3)));
+    obj = OptionalDouble(None).to_object(&mut env);
+    assert!(call!(obj.isEmpty() -> bool));
+    assert_eq!(OptionalDouble::from_object(&obj, &mut env).unwrap(), OptionalDouble(None));
+    // Each class is different
+    assert!(OptionalLong::from_object(&obj, &mut env).is_err());
+    assert!(Optional::<i32>::from_object(&JObject::null(), &mut env).is_err());
+
+    // The types can be used directly in call!
+    let value = call!(static java.util.OptionalInt.of(int(5)) -> OptionalInt);
+    assert_eq!(*value, Some(5));
+    let value = call!(static java.util.Optional.ofNullable(java.lang.Object(null)) -> Optional<String>);
+    assert_eq!(*value, None);
+    let list = List(vec![Optional(Some(1i64)), Optional(None)]);
+    assert_eq!(call!(static java.util.Collections.unmodifiableList(List<Optional<i64>>(list)) -> List<Optional<i64>>), list);
+}
+
+#[test]
+fn big_numbers() {
+    setup_env!(env);
+
+    for value in [0, 1, -1, i64::MIN as i128 - 1, i128::MAX, i128::MIN] {
+        let obj = value.to_object(&mut env);
+        assert_eq!(call!(obj.toString() -> String), value.to_string());
+        assert_eq!(i128::from_object(&obj, &mut env).unwrap(), value);
+    }
+    for value in [0, 255, u64::MAX as u128 + 1, u128::MAX] {
+        let obj = value.to_object(&mut env);
+        assert_eq!(call!(obj.toString() -> String), value.to_string());
+        assert_eq!(u128::from_object(&obj, &mut env).unwrap(), value);
+    }
+    // Values that don't fit
+    let obj = new!(java.math.BigInteger(String("-1")));
+    assert!(matches!(u128::from_object(&obj, &mut env), Err(FromObjectError::Overflow { value, ty: "u128" }) if value == "-1"));
+    let obj = call!(obj.shiftLeft(int(127)) -> java.math.BigInteger);
+    assert_eq!(i128::from_object(&obj, &mut env).unwrap(), i128::MIN);
+    let obj = call!(obj.subtract(java.math.BigInteger(1i128.to_object(&mut env))) -> java.math.BigInteger);
+    assert!(matches!(i128::from_object(&obj, &mut env), Err(FromObjectError::Overflow { ty: "i128", .. })));
+    let obj = (u128::MAX).to_object(&mut env);
+    let obj = call!(obj.add(java.math.BigInteger(1u128.to_object(&mut env))) -> java.math.BigInteger);
+    assert!(matches!(u128::from_object(&obj, &mut env), Err(FromObjectError::Overflow { ty: "u128", .. })));
+    assert!(i128::from_object(&1i64.to_object(&mut env), &mut env).is_err());
+
+    let obj = BigDecimal::from("-1234.50").to_object(&mut env);
+    assert_eq!(call!(obj.scale() -> int), 2);
+    assert_eq!(BigDecimal::from_object(&obj, &mut env).unwrap().as_str(), "-1234.50");
+    // Read without exponent
+    let obj = BigDecimal::from("1.5E+3").to_object(&mut env);
+    assert_eq!(BigDecimal::from_object(&obj, &mut env).unwrap().as_str(), "1500");
+    let value = call!(static java.math.BigDecimal.valueOf(long(1050), int(2)) -> BigDecimal);
+    assert_eq!(value.to_string(), "10.50");
+    let value = call!(static java.math.BigInteger.valueOf(long(-5)) -> i128);
+    assert_eq!(value, -5);
+}
+
+#[test]
+fn unsigned() {
+    setup_env!(env);
+
+    // Checked
+    let obj = Checked(3u32).to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/lang/Integer").unwrap());
+    assert_eq!(Checked::<u32>::from_object(&obj, &mut env).unwrap(), Checked(3));
+    let obj = (-1i32).to_object(&mut env);
+    assert_eq!(u32::from_object(&obj, &mut env).unwrap(), u32::MAX);
+    assert!(matches!(Checked::<u32>::from_object(&obj, &mut env), Err(FromObjectError::Negative { value, ty: "u32" }) if value == "-1"));
+    assert_eq!(
+        Checked::<u32>::from_object(&obj, &mut env).unwrap_err().to_string(),
+        "The value -1 is negative, but the Rust type u32 can't be negative"
+    );
+    let obj = [1i8, -2].to_object(&mut env);
+    assert!(matches!(Vec::<Checked<u8>>::from_object(&obj, &mut env), Err(FromObjectError::Negative { ty: "u8", .. })));
+    let obj = [Checked(1u16), Checked(2)].to_object(&mut env);
+    assert_eq!(Box::<[i16]>::from_object(&obj, &mut env).unwrap().as_ref(), [1, 2]);
+    assert_eq!(Vec::<Checked<u16>>::from_object(&obj, &mut env).unwrap(), [Checked(1), Checked(2)]);
+    assert_eq!(call!(static me.test.Test.getIntArray() -> Vec<Checked<u32>>), [Checked(1), Checked(2), Checked(3)]);
+    // call! converts to and from the primitive
+    let list = call!(static java.util.Arrays.asList(java.lang.Object...(1, 2)) -> java.util.List);
+    assert_eq!(call!(list.size() -> Checked<u32>), Checked(2));
+    assert_eq!(call!(static java.lang.Integer.toString(Checked<u32>(Checked(5))) -> String), "5");
+    assert_eq!(call!(static java.lang.Byte.toString(Checked<u8>(Checked(5))) -> String), "5");
+    let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| call!(static java.lang.Integer.parseInt(String("-1")) -> Checked<u32>)))
+        .unwrap_err();
+    assert!(err.downcast_ref::<String>().unwrap().contains("The value -1 is negative, but the Rust type u32 can't be negative"));
+    assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| call!(static java.lang.Integer.toString(Checked<u32>(Checked(u32::MAX))) -> String))).is_err());
+
+    // Widened
+    let obj = Widened(u32::MAX).to_object(&mut env);
+    assert_eq!(call!(obj.longValue() -> long), u32::MAX as i64);
+    assert_eq!(Widened::<u32>::from_object(&obj, &mut env).unwrap(), Widened(u32::MAX));
+    let obj = (u32::MAX as i64 + 1).to_object(&mut env);
+    assert!(matches!(Widened::<u32>::from_object(&obj, &mut env), Err(FromObjectError::Overflow { ty: "u32", .. })));
+    let obj = (-1i16).to_object(&mut env);
+    assert!(matches!(Widened::<u8>::from_object(&obj, &mut env), Err(FromObjectError::Negative { ty: "u8", .. })));
+    let obj = Widened(u64::MAX).to_object(&mut env);
+    assert_eq!(call!(obj.toString() -> String), u64::MAX.to_string());
+    assert_eq!(*Widened::<u64>::from_object(&obj, &mut env).unwrap(), u64::MAX);
+    let obj = i128::MAX.to_object(&mut env);
+    assert!(matches!(Widened::<u64>::from_object(&obj, &mut env), Err(FromObjectError::Overflow { ty: "u64", .. })));
+    let obj = [Widened(u8::MAX), Widened(0)].to_object(&mut env);
+    assert_eq!(Box::<[i16]>::from_object(&obj, &mut env).unwrap().as_ref(), [255, 0]);
+    assert_eq!(Vec::<Widened<u8>>::from_object(&obj, &mut env).unwrap(), [Widened(u8::MAX), Widened(0)]);
+    // call! converts to and from the wider primitive, except for u64 which is a BigInteger
+    assert_eq!(call!(static java.lang.Integer.toUnsignedLong(int(-1)) -> Widened<u32>), Widened(u32::MAX));
+    assert_eq!(call!(static java.lang.Long.toString(Widened<u32>(Widened(u32::MAX))) -> String), u32::MAX.to_string());
+    assert_eq!(call!(static java.lang.Short.toString(Widened<u8>(Widened(u8::MAX))) -> String), "255");
+    assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| call!(static java.lang.Long.parseLong(String("-1")) -> Widened<u32>))).is_err());
+    assert_eq!(call!(static java.math.BigInteger.valueOf(long(5)) -> Widened<u64>), Widened(5));
+}
+
+#[test]
+fn time() {
+    setup_env!(env);
+
+    let duration = Duration::new(90, 500);
+    let obj = duration.to_object(&mut env);
+    assert_eq!(call!(obj.toString() -> String), "PT1M30.0000005S");
+    assert_eq!(Duration::from_object(&obj, &mut env).unwrap(), duration);
+    let obj = call!(static java.time.Duration.ofMillis(long(-1)) -> java.time.Duration);
+    assert!(matches!(Duration::from_object(&obj, &mut env), Err(FromObjectError::Negative { ty: "Duration", .. })));
+    let value = call!(static java.time.Duration.ofHours(long(2)) -> Duration);
+    assert_eq!(value, Duration::from_secs(2 * 60 * 60));
+
+    let time = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+    let obj = time.to_object(&mut env);
+    assert_eq!(call!(obj.toString() -> String), "2023-11-14T22:13:20.123456789Z");
+    assert_eq!(SystemTime::from_object(&obj, &mut env).unwrap(), time);
+    // Before the epoch
+    let time = UNIX_EPOCH - Duration::new(1, 250_000_000);
+    let obj = time.to_object(&mut env);
+    assert_eq!(call!(obj.toString() -> String), "1969-12-31T23:59:58.750Z");
+    assert_eq!(SystemTime::from_object(&obj, &mut env).unwrap(), time);
+    let obj = call!(static java.time.Instant.now() -> java.time.Instant);
+    assert!(SystemTime::from_object(&obj, &mut env).unwrap() <= SystemTime::now());
+    assert!(Duration::from_object(&obj, &mut env).is_err());
+}
+
+#[test]
+fn paths() {
+    setup_env!(env);
+
+    let path = PathBuf::from("/tmp/ez_jni/file.txt");
+    let obj = path.to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/io/File").unwrap());
+    assert_eq!(call!(obj.getName() -> String), "file.txt");
+    assert_eq!(PathBuf::from_object(&obj, &mut env).unwrap(), path);
+    let obj = Path::new("relative/dir").to_object(&mut env);
+    assert_eq!(call!(obj.getPath() -> String), "relative/dir");
+
+    let obj = NioPath(path.clone()).to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "java/nio/file/Path").unwrap());
+    assert_eq!(call!(obj.toString() -> String), "/tmp/ez_jni/file.txt");
+    assert_eq!(*NioPath::from_object(&obj, &mut env).unwrap(), path);
+    // PathBuf can also be converted from a java.nio.file.Path
+    assert_eq!(PathBuf::from_object(&obj, &mut env).unwrap(), path);
+    assert!(NioPath::from_object(&path.to_object(&mut env), &mut env).is_err());
+    assert!(PathBuf::from_object(&"/tmp".to_object(&mut env), &mut env).is_err());
+
+    let obj = [path.clone(), PathBuf::from("a")].to_object(&mut env);
+    assert!(env.is_instance_of(&obj, "[Ljava/io/File;").unwrap());
+    assert_eq!(Vec::<PathBuf>::from_object(&obj, &mut env).unwrap(), [path.clone(), PathBuf::from("a")]);
+    let file = call!(static java.io.File.createTempFile(String("ez_jni"), String(".tmp")) -> PathBuf);
+    assert!(file.exists());
+    call!(static java.nio.file.Files.delete(NioPath(NioPath(file.clone()))) -> void);
+    assert!(!file.exists());
+
+    // Paths that are not UTF-8 can't be converted to a Java String
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt as _;
+        let path = Path::new(std::ffi::OsStr::from_bytes(b"/tmp/\xFF"));
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| path.to_object(&mut env))).is_err());
+    }
+}
+
+#[test]
+fn java_class() {
+    assert_eq!(String::class_path(), "java/lang/String");
+    assert_eq!(String::signature(), "Ljava/lang/String;");
+    assert_eq!(<&str>::array_signature(), "[Ljava/lang/String;");
+    assert_eq!(i32::class_path(), "java/lang/Integer");
+    assert_eq!(i32::signature(), "I");
+    assert_eq!(Option::<i32>::signature(), "Ljava/lang/Integer;");
+    assert_eq!(Vec::<i32>::class_path(), "[I");
+    assert_eq!(<[Vec<char>]>::signature(), "[[C");
+    assert_eq!(Box::<[Option<String>]>::array_signature(), "[[Ljava/lang/String;");
+    assert_eq!(JObject::class_path(), "java/lang/Object");
+    assert_eq!(std::io::Error::signature(), "Ljava/io/IOException;");
+
+    assert_eq!(MyClass5::class_path(), "me/test/Test");
+    assert_eq!(MyClass5::signature(), "Lme/test/Test;");
+    assert_eq!(Vec::<MyClass5>::signature(), "[Lme/test/Test;");
+    assert_eq!(MyEnumClass2::class_path(), "me/test/Test$SumClass");
+    assert_eq!(MyEnumClass2::array_signature(), "[Lme/test/Test$SumClass;");
+}
+
 #[derive(FromException)]
 #[class(java.lang.Exception)]
 struct MyErr1 {
@@ -258,3 +657,64 @@ fn from_exception() {
         "exception"
     );
 }
+
+#[derive(ToException)]
+#[class(java.lang.IllegalStateException)]
+struct MyErr3;
+impl std::fmt::Display for MyErr3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("exception")
+    }
+}
+
+#[derive(ToException)]
+enum MyErr4 {
+    #[class(java.lang.IllegalArgumentException)]
+    InvalidArg(String),
+    #[class(java.lang.RuntimeException)]
+    Io(#[cause] std::io::Error),
+}
+impl std::fmt::Display for MyErr4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidArg(arg) => write!(f, "Invalid argument: {arg}"),
+            Self::Io(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+#[derive(ToException)]
+#[class(java.lang.RuntimeException)]
+struct MyErr5(#[cause] Option<MyErr3>);
+impl std::fmt::Display for MyErr5 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("exception with optional cause")
+    }
+}
+
+#[test]
+fn to_exception() {
+    setup_env!(env);
+
+    let exception = MyErr3.to_exception(&mut env);
+    assert!(env.is_instance_of(&exception, "java/lang/IllegalStateException").unwrap());
+    assert_eq!(call!(exception.getMessage() -> String), "exception");
+
+    let exception = MyErr4::InvalidArg("arg".to_string()).to_exception(&mut env);
+    assert!(env.is_instance_of(&exception, "java/lang/IllegalArgumentException").unwrap());
+    assert_eq!(call!(exception.getMessage() -> String), "Invalid argument: arg");
+
+    let exception = MyErr4::Io(std::io::Error::new(std::io::ErrorKind::NotFound, "file")).to_exception(&mut env);
+    assert!(env.is_instance_of(&exception, "java/lang/RuntimeException").unwrap());
+    assert_eq!(call!(exception.getMessage() -> String), "IO error: file");
+    let cause = call!(exception.getCause() -> java.lang.Throwable);
+    assert!(env.is_instance_of(&cause, "java/io/FileNotFoundException").unwrap());
+    assert_eq!(call!(cause.getMessage() -> String), "file");
+
+    // A cause can be null
+    let exception = MyErr5(None).to_exception(&mut env);
+    assert!(call!(exception.getCause() -> Option<java.lang.Throwable>).is_none());
+    let exception = MyErr5(Some(MyErr3)).to_exception(&mut env);
+    let cause = call!(exception.getCause() -> java.lang.Throwable);
+    assert!(env.is_instance_of(&cause, "java/lang/IllegalStateException").unwrap());
+}